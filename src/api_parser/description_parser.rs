@@ -0,0 +1,419 @@
+use regex::Regex;
+
+use crate::raw_api::constraints::Constraints;
+use crate::raw_api::type_descriptor::{SemanticType, TypeDescriptor};
+
+/// Extracts additional information about fields and parameters from their description.
+pub trait DescriptionParser {
+    fn parse_allowed_values(&self, type_descriptor: &TypeDescriptor, description_string: &str) -> Vec<String>;
+    fn parse_discriminator_value(&self, type_descriptor: &TypeDescriptor, description_string: &str) -> Option<String>;
+    fn parse_constraints(&self, type_descriptor: &TypeDescriptor, description_string: &str) -> Constraints;
+    fn parse_semantic_type(&self, name: &str, type_descriptor: TypeDescriptor, description_string: &str) -> TypeDescriptor;
+    fn parse_json_serialized(&self, description_string: &str) -> bool;
+}
+
+pub struct DescriptionParserImpl {
+    quoted_value: Regex,
+    parentheses: Regex,
+    value_separator: Regex,
+    excluding_word: Regex,
+    send_values: Regex,
+    discriminator_value: Regex,
+    length_range: Regex,
+    max_length: Regex,
+    value_range: Regex,
+    default_value: Regex,
+}
+
+impl Default for DescriptionParserImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DescriptionParserImpl {
+    const OR_STR: &'static str = " or ";
+    const SENTENCE_END_STR: &'static str = ". ";
+    const UNIX_TIME_STR: &'static str = "unix time";
+    const URL_STR: &'static str = "url";
+    const URL_SUFFIX_STR: &'static str = "_url";
+    const FILE_ID_STR: &'static str = "file_id";
+    const FILE_ID_SUFFIX_STR: &'static str = "_file_id";
+    const JSON_SERIALIZED_STR: &'static str = "JSON-serialized";
+
+    pub fn new() -> Self {
+        DescriptionParserImpl {
+            quoted_value: Regex::new(r"“([^”]*)”").unwrap(),
+            parentheses: Regex::new(r"\([^)]*\)").unwrap(),
+            value_separator: Regex::new(r"^[\s,]*((or|and)[\s,]*)?$").unwrap(),
+            excluding_word: Regex::new(r"(?i)\b(for|example|available)\b").unwrap(),
+            send_values: Regex::new(r"^(Optional\. )?Send ([A-Z]\w*( or [A-Z]\w*)+),").unwrap(),
+            discriminator_value: Regex::new(r", must be (\w+)$").unwrap(),
+            length_range: Regex::new(r"(\d+)-(\d+) characters").unwrap(),
+            max_length: Regex::new(r"up to (\d+) characters").unwrap(),
+            value_range: Regex::new(r"between (\d+) and (\d+)|(\d+) ?[-—] ?(\d+)").unwrap(),
+            default_value: Regex::new(r"[Dd]efaults to “?([\w-]+)”?").unwrap(),
+        }
+    }
+
+    fn is_string(type_descriptor: &TypeDescriptor) -> bool {
+        *Self::without_optional(type_descriptor) == TypeDescriptor::String
+    }
+
+    fn without_optional(type_descriptor: &TypeDescriptor) -> &TypeDescriptor {
+        match type_descriptor {
+            TypeDescriptor::Optional(optional_type) => Self::without_optional(optional_type),
+            _ => type_descriptor
+        }
+    }
+
+    /// Values written without quotes, like the parse modes in "Send Markdown or HTML, if you want ...".
+    fn parse_send_values(&self, description_string: &str) -> Option<Vec<String>> {
+        self.send_values
+            .captures(description_string)
+            .map(|captures| captures[2].split(Self::OR_STR).map(String::from).collect())
+    }
+
+    /// Quoted values like in "Type of chat, can be either “private”, “group”, “supergroup” or “channel”".
+    /// Only lists of at least two values are used. Lists introduced by words like "for example" or
+    /// "available for" only mention some values, so they are skipped.
+    fn parse_quoted_values(&self, description_string: &str) -> Option<Vec<String>> {
+        let mut lists: Vec<(usize, Vec<String>)> = Vec::new();
+        let mut previous_end = None;
+
+        for captures in self.quoted_value.captures_iter(description_string) {
+            let quoted = captures.get(0).unwrap();
+            let value = String::from(&captures[1]);
+
+            match (previous_end, lists.last_mut()) {
+                (Some(end), Some((_, values))) if self.is_value_separator(&description_string[end..quoted.start()]) => values.push(value),
+                _ => lists.push((quoted.start(), vec![value]))
+            }
+            previous_end = Some(quoted.end())
+        }
+
+        lists.into_iter()
+            .filter(|(_, values)| values.len() > 1)
+            .find(|(start, _)| !self.excluding_word.is_match(self.get_sentence_before(description_string, *start)))
+            .map(|(_, values)| Self::remove_duplicates(values))
+    }
+
+    fn is_value_separator(&self, text: &str) -> bool {
+        self.value_separator.is_match(&self.parentheses.replace_all(text, ""))
+    }
+
+    fn get_sentence_before<'a>(&self, description_string: &'a str, position: usize) -> &'a str {
+        let before = &description_string[..position];
+
+        match before.rfind(Self::SENTENCE_END_STR) {
+            Some(sentence_end) => &before[sentence_end + Self::SENTENCE_END_STR.len()..],
+            None => before
+        }
+    }
+
+    /// Length restrictions like "1-4096 characters" or "up to 256 characters".
+    fn parse_length(&self, description_string: &str, constraints: &mut Constraints) {
+        if let Some(captures) = self.length_range.captures(description_string) {
+            constraints.min_length = captures[1].parse().ok();
+            constraints.max_length = captures[2].parse().ok();
+        } else if let Some(captures) = self.max_length.captures(description_string) {
+            constraints.max_length = captures[1].parse().ok();
+        }
+    }
+
+    /// Value restrictions like "Values between 1—100 are accepted" or "should be between 60 and 86400".
+    fn parse_value_range(&self, description_string: &str, constraints: &mut Constraints) {
+        if let Some(captures) = self.value_range.captures(description_string) {
+            let min_value = captures.get(1).or_else(|| captures.get(3)).unwrap();
+            let max_value = captures.get(2).or_else(|| captures.get(4)).unwrap();
+            constraints.min_value = min_value.as_str().parse().ok();
+            constraints.max_value = max_value.as_str().parse().ok();
+        }
+    }
+
+    /// Default values like "Defaults to 100". Boolean values are written in lowercase.
+    fn parse_default_value(&self, type_descriptor: &TypeDescriptor, description_string: &str) -> Option<String> {
+        let default_value = self.default_value.captures(description_string)?[1].to_string();
+
+        match type_descriptor {
+            TypeDescriptor::Boolean => Some(default_value.to_lowercase()),
+            TypeDescriptor::Integer | TypeDescriptor::Integer64 | TypeDescriptor::Float => default_value.parse::<f64>().ok().map(|_| default_value),
+            TypeDescriptor::String => Some(default_value),
+            _ => None
+        }
+    }
+
+    fn find_semantic_type(name: &str, type_descriptor: &TypeDescriptor, description_string: &str) -> Option<SemanticType> {
+        match type_descriptor {
+            TypeDescriptor::Integer if description_string.to_lowercase().contains(Self::UNIX_TIME_STR) => Some(SemanticType::UnixTime),
+            TypeDescriptor::String if name == Self::URL_STR || name.ends_with(Self::URL_SUFFIX_STR) => Some(SemanticType::Url),
+            TypeDescriptor::String if name == Self::FILE_ID_STR || name.ends_with(Self::FILE_ID_SUFFIX_STR) => Some(SemanticType::FileId),
+            _ => None
+        }
+    }
+
+    /// Wrap the primitive inside of optionals and arrays into the semantic type.
+    fn wrap_semantic_type(type_descriptor: TypeDescriptor, semantic_type: SemanticType) -> TypeDescriptor {
+        match type_descriptor {
+            TypeDescriptor::Optional(optional_type) => TypeDescriptor::Optional(Box::new(Self::wrap_semantic_type(*optional_type, semantic_type))),
+            TypeDescriptor::ArrayOf(array_type) => TypeDescriptor::ArrayOf(Box::new(Self::wrap_semantic_type(*array_type, semantic_type))),
+            _ => TypeDescriptor::Semantic(semantic_type, Box::new(type_descriptor))
+        }
+    }
+
+    fn remove_duplicates(values: Vec<String>) -> Vec<String> {
+        values.into_iter().fold(Vec::new(), |mut unique_values, value| {
+            if !unique_values.contains(&value) {
+                unique_values.push(value)
+            }
+            unique_values
+        })
+    }
+}
+
+impl DescriptionParser for DescriptionParserImpl {
+    /// Get the values a String field or parameter is restricted to. The result is empty
+    /// if the description does not mention such values.
+    fn parse_allowed_values(&self, type_descriptor: &TypeDescriptor, description_string: &str) -> Vec<String> {
+        if !Self::is_string(type_descriptor) {
+            return Vec::new();
+        }
+
+        self.parse_send_values(description_string)
+            .or_else(|| self.parse_quoted_values(description_string))
+            .unwrap_or_default()
+    }
+
+    /// Get the constant value of a String field which tells the members of a union apart,
+    /// like the type field described with "Type of the result, must be photo".
+    fn parse_discriminator_value(&self, type_descriptor: &TypeDescriptor, description_string: &str) -> Option<String> {
+        if *type_descriptor != TypeDescriptor::String {
+            return None;
+        }
+
+        self.discriminator_value
+            .captures(description_string.trim())
+            .map(|captures| String::from(&captures[1]))
+    }
+
+    /// Get the length, value and default restrictions of a primitive field or parameter.
+    /// Lengths are only used for Strings and value ranges only for numbers.
+    fn parse_constraints(&self, type_descriptor: &TypeDescriptor, description_string: &str) -> Constraints {
+        let type_descriptor = Self::without_optional(type_descriptor);
+        let mut constraints = Constraints::default();
+
+        match type_descriptor {
+            TypeDescriptor::String => self.parse_length(description_string, &mut constraints),
+            TypeDescriptor::Integer | TypeDescriptor::Integer64 | TypeDescriptor::Float => self.parse_value_range(description_string, &mut constraints),
+            _ => {}
+        }
+        constraints.default_value = self.parse_default_value(type_descriptor, description_string);
+
+        constraints
+    }
+
+    /// Mark primitives with a special meaning: Integers described as "Unix time", and Strings
+    /// named like "url"/"photo_url" or "file_id"/"photo_file_id".
+    fn parse_semantic_type(&self, name: &str, type_descriptor: TypeDescriptor, description_string: &str) -> TypeDescriptor {
+        let mut base_type = Self::without_optional(&type_descriptor);
+        while let TypeDescriptor::ArrayOf(array_type) = base_type {
+            base_type = Self::without_optional(array_type);
+        }
+
+        match Self::find_semantic_type(name, base_type, description_string) {
+            Some(semantic_type) => Self::wrap_semantic_type(type_descriptor, semantic_type),
+            None => type_descriptor
+        }
+    }
+
+    /// Parameters like reply_markup are "A JSON-serialized object" and have to be encoded as a JSON string in form data.
+    fn parse_json_serialized(&self, description_string: &str) -> bool {
+        description_string.contains(Self::JSON_SERIALIZED_STR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_parser::description_parser::{DescriptionParser, DescriptionParserImpl};
+    use crate::raw_api::constraints::Constraints;
+    use crate::raw_api::type_descriptor::{SemanticType, TypeDescriptor};
+
+    fn to_strings(values: Vec<&str>) -> Vec<String> {
+        values.into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn success_allowed_values_can_be_either() {
+        let description = "Type of chat, can be either “private”, “group”, “supergroup” or “channel”";
+
+        let allowed_values = DescriptionParserImpl::new().parse_allowed_values(&TypeDescriptor::String, description);
+
+        assert_eq!(allowed_values, to_strings(vec!["private", "group", "supergroup", "channel"]))
+    }
+
+    #[test]
+    fn success_allowed_values_with_explanations() {
+        let description = "Type of the entity. Can be “mention” (@username), “hashtag” (#hashtag) or “text_link” (for clickable text URLs)";
+
+        let allowed_values = DescriptionParserImpl::new().parse_allowed_values(&TypeDescriptor::String, description);
+
+        assert_eq!(allowed_values, to_strings(vec!["mention", "hashtag", "text_link"]))
+    }
+
+    #[test]
+    fn success_allowed_values_with_default() {
+        let description = "Poll type, “quiz” or “regular”, defaults to “regular”";
+
+        let allowed_values = DescriptionParserImpl::new().parse_allowed_values(&TypeDescriptor::Optional(Box::new(TypeDescriptor::String)), description);
+
+        assert_eq!(allowed_values, to_strings(vec!["quiz", "regular"]))
+    }
+
+    #[test]
+    fn success_allowed_values_without_quotes() {
+        let description = "Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.";
+
+        let allowed_values = DescriptionParserImpl::new().parse_allowed_values(&TypeDescriptor::String, description);
+
+        assert_eq!(allowed_values, to_strings(vec!["Markdown", "HTML"]))
+    }
+
+    #[test]
+    fn success_no_allowed_values_for_examples() {
+        let description = "Optional. Foursquare type of the venue. (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)";
+
+        let allowed_values = DescriptionParserImpl::new().parse_allowed_values(&TypeDescriptor::String, description);
+
+        assert!(allowed_values.is_empty())
+    }
+
+    #[test]
+    fn success_no_allowed_values_for_single_value() {
+        let description = "Optional. For “text_link” only, url that will be opened after user taps on the text";
+
+        let allowed_values = DescriptionParserImpl::new().parse_allowed_values(&TypeDescriptor::String, description);
+
+        assert!(allowed_values.is_empty())
+    }
+
+    #[test]
+    fn success_no_allowed_values_for_non_string() {
+        let description = "Type of chat, can be either “private”, “group”, “supergroup” or “channel”";
+
+        let allowed_values = DescriptionParserImpl::new().parse_allowed_values(&TypeDescriptor::Integer, description);
+
+        assert!(allowed_values.is_empty())
+    }
+
+    #[test]
+    fn success_discriminator_value() {
+        let description = "Type of the result, must be photo";
+
+        let discriminator_value = DescriptionParserImpl::new().parse_discriminator_value(&TypeDescriptor::String, description);
+
+        assert_eq!(discriminator_value, Some(String::from("photo")))
+    }
+
+    #[test]
+    fn success_no_discriminator_value() {
+        let description = "New score, must be non-negative";
+
+        let discriminator_value = DescriptionParserImpl::new().parse_discriminator_value(&TypeDescriptor::Integer, description);
+
+        assert_eq!(discriminator_value, None)
+    }
+
+    #[test]
+    fn success_length_constraints() {
+        let description = "Text of the message to be sent, 1-4096 characters after entities parsing";
+
+        let constraints = DescriptionParserImpl::new().parse_constraints(&TypeDescriptor::String, description);
+
+        assert_eq!(constraints, Constraints { min_length: Some(1), max_length: Some(4096), ..Constraints::default() })
+    }
+
+    #[test]
+    fn success_max_length_constraint() {
+        let description = "Text of the query (up to 256 characters)";
+
+        let constraints = DescriptionParserImpl::new().parse_constraints(&TypeDescriptor::String, description);
+
+        assert_eq!(constraints, Constraints { max_length: Some(256), ..Constraints::default() })
+    }
+
+    #[test]
+    fn success_value_constraints() {
+        let description = "Limits the number of updates to be retrieved. Values between 1—100 are accepted. Defaults to 100.";
+
+        let constraints = DescriptionParserImpl::new().parse_constraints(&TypeDescriptor::Optional(Box::new(TypeDescriptor::Integer)), description);
+
+        let expected = Constraints { min_value: Some(1), max_value: Some(100), default_value: Some(String::from("100")), ..Constraints::default() };
+        assert_eq!(constraints, expected)
+    }
+
+    #[test]
+    fn success_value_constraints_with_and() {
+        let description = "Optional. Period in seconds for which the location can be updated, should be between 60 and 86400.";
+
+        let constraints = DescriptionParserImpl::new().parse_constraints(&TypeDescriptor::Integer, description);
+
+        assert_eq!(constraints, Constraints { min_value: Some(60), max_value: Some(86400), ..Constraints::default() })
+    }
+
+    #[test]
+    fn success_default_values() {
+        let parser = DescriptionParserImpl::new();
+
+        let boolean_constraints = parser.parse_constraints(&TypeDescriptor::Boolean, "True, if the poll needs to be anonymous, defaults to True");
+        let string_constraints = parser.parse_constraints(&TypeDescriptor::String, "Poll type, “quiz” or “regular”, defaults to “regular”");
+
+        assert_eq!(boolean_constraints.default_value, Some(String::from("true")));
+        assert_eq!(string_constraints.default_value, Some(String::from("regular")))
+    }
+
+    #[test]
+    fn success_no_constraints_for_arrays() {
+        let description = "A JSON-serialized list of answer options, 2-10 strings 1-100 characters each";
+
+        let constraints = DescriptionParserImpl::new().parse_constraints(&TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::String)), description);
+
+        assert_eq!(constraints, Constraints::default())
+    }
+
+    #[test]
+    fn success_unix_time_semantic_type() {
+        let description = "Optional. Date the message was last edited in Unix time";
+        let type_descriptor = TypeDescriptor::Optional(Box::new(TypeDescriptor::Integer));
+
+        let semantic_type = DescriptionParserImpl::new().parse_semantic_type("edit_date", type_descriptor, description);
+
+        let expected = TypeDescriptor::Optional(Box::new(TypeDescriptor::Semantic(SemanticType::UnixTime, Box::new(TypeDescriptor::Integer))));
+        assert_eq!(semantic_type, expected)
+    }
+
+    #[test]
+    fn success_url_and_file_id_semantic_types() {
+        let parser = DescriptionParserImpl::new();
+
+        let url_type = parser.parse_semantic_type("thumb_url", TypeDescriptor::String, "Url of the thumbnail for the result");
+        let file_id_type = parser.parse_semantic_type("photo_file_id", TypeDescriptor::String, "A valid file identifier of the photo");
+
+        assert_eq!(url_type, TypeDescriptor::Semantic(SemanticType::Url, Box::new(TypeDescriptor::String)));
+        assert_eq!(file_id_type, TypeDescriptor::Semantic(SemanticType::FileId, Box::new(TypeDescriptor::String)))
+    }
+
+    #[test]
+    fn success_no_semantic_type() {
+        let semantic_type = DescriptionParserImpl::new().parse_semantic_type("hide_url", TypeDescriptor::Boolean, "Optional. Pass True, if you don't want the URL to be shown in the message");
+
+        assert_eq!(semantic_type, TypeDescriptor::Boolean)
+    }
+
+    #[test]
+    fn success_json_serialized() {
+        let parser = DescriptionParserImpl::new();
+
+        assert!(parser.parse_json_serialized("Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user."));
+        assert!(!parser.parse_json_serialized("Text of the message to be sent, 1-4096 characters after entities parsing"))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+/// Decides what happens with an error: in strict mode it is returned, otherwise it is
+/// collected as a diagnostic and the part of the api that caused it is skipped.
+pub struct Diagnostics<D> {
+    strict: bool,
+    pub collected: Vec<D>,
+}
+
+impl<D> Diagnostics<D> {
+    pub fn strict() -> Self {
+        Diagnostics { strict: true, collected: Vec::new() }
+    }
+
+    pub fn lenient() -> Self {
+        Diagnostics { strict: false, collected: Vec::new() }
+    }
+
+    pub fn handle<T, E, F: FnOnce(E) -> D>(&mut self, result: Result<T, E>, to_diagnostic: F) -> Result<Option<T>, E> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(error) if self.strict => Err(error),
+            Err(error) => {
+                self.collected.push(to_diagnostic(error));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Report a problem which doesn't need to skip anything, like an unknown value with a sensible default.
+    pub fn warn<E, F: FnOnce(E) -> D>(&mut self, error: E, to_diagnostic: F) -> Result<(), E> {
+        self.handle(Err::<(), E>(error), to_diagnostic).map(|_| ())
+    }
+}
\ No newline at end of file
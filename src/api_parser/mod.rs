@@ -1,91 +1,451 @@
 use crate::api_parser::scraper::Scraper;
-use crate::api_parser::tables::{DtoTables, MethodTables, DtoTable, DtoRow, MethodTable, MethodRow};
-use crate::raw_api::{RawApi, RawDtos, RawMethods};
+use crate::api_parser::tables::{DtoTables, MethodTables, DtoTable, DtoRow, MethodTable, MethodRow, UnionTables, UnionTable};
+use crate::raw_api::{RawApi, RawDtos, RawMethods, RawUnions};
 use crate::raw_api::raw_dto::RawDto;
 use crate::raw_api::raw_field::RawField;
 use crate::raw_api::raw_method::RawMethod;
 use crate::raw_api::raw_parameter::RawParameter;
+use crate::raw_api::raw_union::RawUnion;
 use crate::api_parser::type_parser::TypeParser;
+use crate::api_parser::diagnostics::Diagnostics;
+use crate::raw_api::requirement::Requirement;
+use crate::api_parser::description_parser::DescriptionParser;
+use crate::api_parser::type_expression::TypeExpressionError;
+use std::fmt::Formatter;
 
 pub mod scraper;
 pub mod type_parser;
+pub mod type_expression;
+pub mod description_parser;
+pub mod diagnostics;
 mod tables;
 
-pub struct ApiParser<S: Scraper, T: TypeParser> {
+type ParserDiagnostics = Diagnostics<ApiParserDiagnostic>;
+
+pub struct ApiParser<S: Scraper, T: TypeParser, D: DescriptionParser> {
     scraper: S,
     type_parser: T,
+    description_parser: D,
 }
 
-impl<S: Scraper, T: TypeParser> ApiParser<S, T> {
-    pub fn new(scraper: S, type_parser: T) -> Self {
+impl<S: Scraper, T: TypeParser, D: DescriptionParser> ApiParser<S, T, D> {
+
+    pub fn new(scraper: S, type_parser: T, description_parser: D) -> Self {
         ApiParser {
             scraper,
-            type_parser
+            type_parser,
+            description_parser
         }
     }
 
-    pub fn parse(&self) -> RawApi {
-        let raw_dtos = self.parse_dto_tables(self.scraper.get_dto_tables());
-        let raw_methods = self.parse_method_tables(self.scraper.get_method_tables());
-        RawApi {
+    /// Parse the api and stop at the first problem.
+    pub fn parse(&self) -> Result<RawApi, ApiParserError> {
+        self.parse_with_diagnostics(&mut Diagnostics::strict())
+    }
+
+    /// Parse the api and return the problems as warnings instead of stopping. DTOs and methods
+    /// with an invalid type are skipped, parameters with an unknown requirement are required.
+    pub fn parse_lenient(&self) -> Result<(RawApi, Vec<ApiParserDiagnostic>), ApiParserError> {
+        let mut diagnostics = Diagnostics::lenient();
+        let raw_api = self.parse_with_diagnostics(&mut diagnostics)?;
+        Ok((raw_api, diagnostics.collected))
+    }
+
+    fn parse_with_diagnostics(&self, diagnostics: &mut ParserDiagnostics) -> Result<RawApi, ApiParserError> {
+        let raw_dtos = self.parse_dto_tables(self.scraper.get_dto_tables(), diagnostics)?;
+        let raw_unions = self.parse_union_tables(self.scraper.get_union_tables());
+        let raw_methods = self.parse_method_tables(self.scraper.get_method_tables(), diagnostics)?;
+        let mut raw_api = RawApi {
             raw_dtos,
             raw_methods,
-        }
+            raw_unions,
+            categories: self.scraper.get_categories(),
+        };
+        raw_api.update_file_flags();
+        Ok(raw_api)
     }
 
-    fn parse_dto_tables(&self, dto_tables: DtoTables) -> RawDtos {
+    fn parse_dto_tables(&self, dto_tables: DtoTables, diagnostics: &mut ParserDiagnostics) -> Result<RawDtos, ApiParserError> {
         let mut raw_dtos = Vec::new();
         for table in dto_tables {
-            raw_dtos.push(self.parse_table_to_dto(table))
+            let name = table.name.clone();
+            let result = self.parse_table_to_dto(table);
+            if let Some(raw_dto) = diagnostics.handle(result, |error| ApiParserDiagnostic::skipped(name, error))? {
+                raw_dtos.push(raw_dto)
+            }
         }
-        raw_dtos
+        Ok(raw_dtos)
     }
 
-    fn parse_table_to_dto(&self, table: DtoTable) -> RawDto {
+    fn parse_table_to_dto(&self, table: DtoTable) -> Result<RawDto, ApiParserError> {
         let mut fields = Vec::new();
         for row in table.rows {
-            fields.push(self.parse_row_to_field(row))
+            fields.push(self.parse_row_to_field(&table.name, row)?)
         }
-        RawDto {
+        Ok(RawDto {
             name: table.name,
+            description: table.description,
+            category: table.category,
             fields,
-        }
+        })
     }
 
-    fn parse_row_to_field(&self, row: DtoRow) -> RawField {
+    fn parse_row_to_field(&self, dto_name: &str, row: DtoRow) -> Result<RawField, ApiParserError> {
         let name = row.field_string;
-        let field_type = self.type_parser.parse_field_type(row.type_string, row.description_string);
-        RawField {
+        let field_type = match self.type_parser.parse_field_type(row.type_string, row.description_string.clone()) {
+            Ok(field_type) => field_type,
+            Err(error) => return Err(ApiParserError::InvalidFieldType { dto_name: String::from(dto_name), field_name: name, error })
+        };
+        let allowed_values = self.description_parser.parse_allowed_values(&field_type, &row.description_string);
+        let discriminator_value = self.description_parser.parse_discriminator_value(&field_type, &row.description_string);
+        let constraints = self.description_parser.parse_constraints(&field_type, &row.description_string);
+        let field_type = self.description_parser.parse_semantic_type(&name, field_type, &row.description_string);
+        Ok(RawField {
             name,
-            field_type
-        }
+            field_type,
+            description: row.description_string,
+            allowed_values,
+            discriminator_value,
+            constraints
+        })
     }
 
-    fn parse_method_tables(&self, method_tables: MethodTables) -> RawMethods {
+    fn parse_method_tables(&self, method_tables: MethodTables, diagnostics: &mut ParserDiagnostics) -> Result<RawMethods, ApiParserError> {
         let mut raw_methods = Vec::new();
         for table in method_tables {
-            raw_methods.push(self.parse_table_to_method(table))
+            let name = table.name.clone();
+            let result = self.parse_table_to_method(table, diagnostics);
+            if let Some(raw_method) = diagnostics.handle(result, |error| ApiParserDiagnostic::skipped(name, error))? {
+                raw_methods.push(raw_method)
+            }
         }
-        raw_methods
+        Ok(raw_methods)
     }
 
-    fn parse_table_to_method(&self, table: MethodTable) -> RawMethod {
+    fn parse_table_to_method(&self, table: MethodTable, diagnostics: &mut ParserDiagnostics) -> Result<RawMethod, ApiParserError> {
         let mut parameters = Vec::new();
         for row in table.rows {
-            parameters.push(self.parse_row_to_parameter(row))
+            parameters.push(self.parse_row_to_parameter(&table.name, row, diagnostics)?)
         }
-        RawMethod {
+        let return_type = match self.type_parser.parse_return_type(table.description.clone()) {
+            Ok(return_type) => return_type,
+            Err(error) => return Err(ApiParserError::InvalidReturnType { method_name: table.name, error })
+        };
+        Ok(RawMethod {
             name: table.name,
+            description: table.description,
+            category: table.category,
             parameters,
-        }
+            return_type,
+            requires_multipart: false
+        })
     }
 
-    fn parse_row_to_parameter(&self, row: MethodRow) -> RawParameter {
+    fn parse_row_to_parameter(&self, method_name: &str, row: MethodRow, diagnostics: &mut ParserDiagnostics) -> Result<RawParameter, ApiParserError> {
         let name = row.parameter_string;
-        let parameter_type = self.type_parser.parse_parameter_type(row.type_string, row.required_string);
-        RawParameter {
+        let requirement = self.type_parser.parse_requirement(row.required_string);
+        if let Requirement::Unknown(required_string) = &requirement {
+            let error = ApiParserError::UnknownRequirement { method_name: String::from(method_name), parameter_name: name.clone(), requirement: String::from(required_string.trim()) };
+            diagnostics.warn(error, ApiParserDiagnostic::warning)?
+        }
+        let parameter_type = match self.type_parser.parse_parameter_type(row.type_string, row.description_string.clone(), &requirement) {
+            Ok(parameter_type) => parameter_type,
+            Err(error) => return Err(ApiParserError::InvalidParameterType { method_name: String::from(method_name), parameter_name: name, error })
+        };
+        let allowed_values = self.description_parser.parse_allowed_values(&parameter_type, &row.description_string);
+        let constraints = self.description_parser.parse_constraints(&parameter_type, &row.description_string);
+        let parameter_type = self.description_parser.parse_semantic_type(&name, parameter_type, &row.description_string);
+        let json_serialized = self.description_parser.parse_json_serialized(&row.description_string);
+        Ok(RawParameter {
             name,
-            parameter_type
+            parameter_type,
+            requirement,
+            description: row.description_string,
+            allowed_values,
+            constraints,
+            is_file: false,
+            json_serialized
+        })
+    }
+
+    fn parse_union_tables(&self, union_tables: UnionTables) -> RawUnions {
+        let mut raw_unions = Vec::new();
+        for table in union_tables {
+            raw_unions.push(self.parse_table_to_union(table))
+        }
+        raw_unions
+    }
+
+    fn parse_table_to_union(&self, table: UnionTable) -> RawUnion {
+        RawUnion {
+            name: table.name,
+            description: table.description,
+            category: table.category,
+            members: table.members
+        }
+    }
+}
+
+/// A type of the api could not be parsed. Contains the DTO and field or the
+/// method and parameter the type belongs to.
+#[derive(Debug, PartialEq)]
+pub enum ApiParserError {
+    InvalidFieldType { dto_name: String, field_name: String, error: TypeExpressionError },
+    InvalidParameterType { method_name: String, parameter_name: String, error: TypeExpressionError },
+    InvalidReturnType { method_name: String, error: TypeExpressionError },
+    /// The Required column of a parameter contains something else than "Yes" or "Optional". In lenient mode it is treated as required.
+    UnknownRequirement { method_name: String, parameter_name: String, requirement: String },
+}
+
+impl std::error::Error for ApiParserError {}
+
+/// A problem that was reported as a warning in lenient mode. Contains the name of the DTO or
+/// method that was skipped because of it, if any.
+#[derive(Debug, PartialEq)]
+pub struct ApiParserDiagnostic {
+    pub skipped_name: Option<String>,
+    pub error: ApiParserError,
+}
+
+impl ApiParserDiagnostic {
+    fn skipped(skipped_name: String, error: ApiParserError) -> Self {
+        ApiParserDiagnostic { skipped_name: Some(skipped_name), error }
+    }
+
+    fn warning(error: ApiParserError) -> Self {
+        ApiParserDiagnostic { skipped_name: None, error }
+    }
+}
+
+impl std::fmt::Display for ApiParserDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.skipped_name {
+            Some(skipped_name) => write!(f, "Skipped {}: {}", skipped_name, self.error),
+            None => write!(f, "{}", self.error)
+        }
+    }
+}
+
+impl std::fmt::Display for ApiParserError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiParserError::InvalidFieldType { dto_name, field_name, error } => write!(f, "The type of the field {}.{} is invalid: {}", dto_name, field_name, error),
+            ApiParserError::InvalidParameterType { method_name, parameter_name, error } => write!(f, "The type of the parameter {}.{} is invalid: {}", method_name, parameter_name, error),
+            ApiParserError::InvalidReturnType { method_name, error } => write!(f, "The return type of the method {} is invalid: {}", method_name, error),
+            ApiParserError::UnknownRequirement { method_name, parameter_name, requirement } => write!(f, "The parameter {}.{} has the unknown requirement \"{}\"", method_name, parameter_name, requirement),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_parser::{ApiParser, ApiParserDiagnostic, ApiParserError};
+    use crate::api_parser::scraper::{Scraper, ScraperImpl};
+    use crate::api_parser::tables::{DtoRow, DtoTable, DtoTables, MethodRow, MethodTable, MethodTables, UnionTable, UnionTables};
+    use crate::api_parser::type_expression::TypeExpressionError;
+    use crate::api_parser::type_parser::TypeParserImpl;
+    use crate::api_parser::description_parser::DescriptionParserImpl;
+    use crate::raw_api::raw_union::RawUnion;
+    use crate::raw_api::requirement::Requirement;
+
+    const UNION_HTML: &str = r#"
+    <h4><a class="anchor" name="inputmedia"><i class="anchor-icon"></i></a>InputMedia</h4>
+    <p>This object represents the content of a media message to be sent. It should be one of</p>
+    <ul>
+        <li><a href=#inputmediaphoto>InputMediaPhoto</a></li>
+        <li><a href=#inputmediavideo>InputMediaVideo</a></li>
+    </ul>
+    <h4><a class="anchor" name="inlinequeryresult"><i class="anchor-icon"></i></a>InlineQueryResult</h4>
+    <p>This object represents one result of an inline query. Telegram clients currently support results of the following 2 types:</p>
+    <ul>
+        <li><a href=#inlinequeryresultarticle>InlineQueryResultArticle</a></li>
+        <li><a href=#inlinequeryresultaudio>InlineQueryResultAudio</a></li>
+    </ul>
+    "#;
+
+    const DTO_WITH_LIST_HTML: &str = r#"
+    <h4><a class="anchor" name="callbackgame"><i class="anchor-icon"></i></a>CallbackGame</h4>
+    <p>A placeholder, currently holds no information. Please note:</p>
+    <ul>
+        <li>Games are only available for bots.</li>
+        <li>Use BotFather to set up your game.</li>
+    </ul>
+    "#;
+
+    struct InvalidTypeScraper;
+
+    impl Scraper for InvalidTypeScraper {
+        fn get_dto_tables(&self) -> DtoTables {
+            let mut table = DtoTable::new(String::from("FooDto"), String::from("A nice Dto."));
+            table.add_row(DtoRow::new(String::from("foo"), String::from("Foo or"), String::from("A foo.")));
+            vec![table]
+        }
+
+        fn get_method_tables(&self) -> MethodTables {
+            let mut send_foo = MethodTable::new(String::from("sendFoo"), String::from("Sends a foo."));
+            send_foo.add_row(MethodRow::new(String::from("foo"), String::from("Array of"), String::from("Yes"), String::from("A foo.")));
+            let send_message = MethodTable::new(String::from("sendMessage"), String::from("Sends a message."));
+            vec![send_foo, send_message]
+        }
+
+        fn get_union_tables(&self) -> UnionTables {
+            Vec::new()
+        }
+
+        fn get_categories(&self) -> Vec<String> {
+            Vec::new()
         }
     }
+
+    struct UnknownRequirementScraper;
+
+    impl Scraper for UnknownRequirementScraper {
+        fn get_dto_tables(&self) -> DtoTables {
+            Vec::new()
+        }
+
+        fn get_method_tables(&self) -> MethodTables {
+            let mut send_message = MethodTable::new(String::from("sendMessage"), String::from("Sends a message."));
+            send_message.add_row(MethodRow::new(String::from("text"), String::from("String"), String::from("Sometimes"), String::from("The text.")));
+            vec![send_message]
+        }
+
+        fn get_union_tables(&self) -> UnionTables {
+            Vec::new()
+        }
+
+        fn get_categories(&self) -> Vec<String> {
+            Vec::new()
+        }
+    }
+
+    struct FileScraper;
+
+    impl Scraper for FileScraper {
+        fn get_dto_tables(&self) -> DtoTables {
+            let input_file = DtoTable::new(String::from("InputFile"), String::from("A file."));
+            let mut input_media_video = DtoTable::new(String::from("InputMediaVideo"), String::from("A video."));
+            input_media_video.add_row(DtoRow::new(String::from("thumb"), String::from("InputFile or String"), String::from("Optional. A thumbnail.")));
+            vec![input_file, input_media_video]
+        }
+
+        fn get_method_tables(&self) -> MethodTables {
+            let mut send_document = MethodTable::new(String::from("sendDocument"), String::from("Sends a document."));
+            send_document.add_row(MethodRow::new(String::from("document"), String::from("InputFile or String"), String::from("Yes"), String::from("A document.")));
+            let mut edit_message_media = MethodTable::new(String::from("editMessageMedia"), String::from("Edits media."));
+            edit_message_media.add_row(MethodRow::new(String::from("media"), String::from("InputMedia"), String::from("Yes"), String::from("The media.")));
+            let mut send_message = MethodTable::new(String::from("sendMessage"), String::from("Sends a message."));
+            send_message.add_row(MethodRow::new(String::from("text"), String::from("String"), String::from("Yes"), String::from("The text.")));
+            vec![send_document, edit_message_media, send_message]
+        }
+
+        fn get_union_tables(&self) -> UnionTables {
+            vec![UnionTable::new(String::from("InputMedia"), String::from("Some media."), vec![String::from("InputMediaVideo")])]
+        }
+
+        fn get_categories(&self) -> Vec<String> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn success_files_require_multipart() {
+        let parser = ApiParser::new(FileScraper, TypeParserImpl, DescriptionParserImpl::new());
+
+        let raw_methods = parser.parse().unwrap().raw_methods;
+
+        let is_file: Vec<bool> = raw_methods.iter().map(|raw_method| raw_method.parameters[0].is_file).collect();
+        let requires_multipart: Vec<bool> = raw_methods.iter().map(|raw_method| raw_method.requires_multipart).collect();
+        assert_eq!(is_file, vec![true, false, false]);
+        assert_eq!(requires_multipart, vec![true, true, false])
+    }
+
+    #[test]
+    fn failure_invalid_field_type() {
+        let parser = ApiParser::new(InvalidTypeScraper, TypeParserImpl, DescriptionParserImpl::new());
+
+        let expected = ApiParserError::InvalidFieldType {
+            dto_name: String::from("FooDto"),
+            field_name: String::from("foo"),
+            error: TypeExpressionError::UnexpectedEnd { expression: String::from("Foo or") },
+        };
+        assert_eq!(parser.parse().unwrap_err(), expected)
+    }
+
+    #[test]
+    fn success_invalid_types_lenient() {
+        let parser = ApiParser::new(InvalidTypeScraper, TypeParserImpl, DescriptionParserImpl::new());
+
+        let (raw_api, diagnostics) = parser.parse_lenient().unwrap();
+
+        let method_names: Vec<&String> = raw_api.raw_methods.iter().map(|raw_method| &raw_method.name).collect();
+        assert!(raw_api.raw_dtos.is_empty());
+        assert_eq!(method_names, vec!["sendMessage"]);
+        let skipped_names: Vec<Option<String>> = diagnostics.into_iter().map(|diagnostic| diagnostic.skipped_name).collect();
+        assert_eq!(skipped_names, vec![Some(String::from("FooDto")), Some(String::from("sendFoo"))])
+    }
+
+    #[test]
+    fn success_unknown_requirement_lenient() {
+        let parser = ApiParser::new(UnknownRequirementScraper, TypeParserImpl, DescriptionParserImpl::new());
+
+        let (raw_api, diagnostics) = parser.parse_lenient().unwrap();
+
+        let parameter = &raw_api.raw_methods[0].parameters[0];
+        assert_eq!(parameter.requirement, Requirement::Unknown(String::from("Sometimes")));
+        assert!(parameter.requirement.is_required());
+        assert_eq!(diagnostics, vec![ApiParserDiagnostic { skipped_name: None, error: create_unknown_requirement_error() }])
+    }
+
+    #[test]
+    fn failure_unknown_requirement_strict() {
+        let parser = ApiParser::new(UnknownRequirementScraper, TypeParserImpl, DescriptionParserImpl::new());
+
+        assert_eq!(parser.parse().unwrap_err(), create_unknown_requirement_error())
+    }
+
+    fn create_unknown_requirement_error() -> ApiParserError {
+        ApiParserError::UnknownRequirement {
+            method_name: String::from("sendMessage"),
+            parameter_name: String::from("text"),
+            requirement: String::from("Sometimes"),
+        }
+    }
+
+    #[test]
+    fn success_union_from_list() {
+        let scraper = ScraperImpl::from_html(UNION_HTML.as_bytes()).unwrap();
+        let parser = ApiParser::new(scraper, TypeParserImpl, DescriptionParserImpl::new());
+
+        let raw_api = parser.parse().unwrap();
+
+        let expected = vec![
+            RawUnion {
+                name: String::from("InputMedia"),
+                description: String::from("This object represents the content of a media message to be sent. It should be one of"),
+                category: None,
+                members: vec![String::from("InputMediaPhoto"), String::from("InputMediaVideo")]
+            },
+            RawUnion {
+                name: String::from("InlineQueryResult"),
+                description: String::from("This object represents one result of an inline query. Telegram clients currently support results of the following 2 types:"),
+                category: None,
+                members: vec![String::from("InlineQueryResultArticle"), String::from("InlineQueryResultAudio")]
+            }
+        ];
+        assert_eq!(raw_api.raw_unions, expected);
+        assert!(raw_api.raw_dtos.is_empty())
+    }
+
+    #[test]
+    fn success_dto_with_unrelated_list() {
+        let scraper = ScraperImpl::from_html(DTO_WITH_LIST_HTML.as_bytes()).unwrap();
+        let parser = ApiParser::new(scraper, TypeParserImpl, DescriptionParserImpl::new());
+
+        let raw_api = parser.parse().unwrap();
+
+        let dto_names: Vec<&String> = raw_api.raw_dtos.iter().map(|raw_dto| &raw_dto.name).collect();
+        assert_eq!(dto_names, vec!["CallbackGame"]);
+        assert!(raw_api.raw_unions.is_empty())
+    }
 }
\ No newline at end of file
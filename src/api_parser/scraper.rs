@@ -1,17 +1,25 @@
 use select::document::Document;
 use select::node::Node;
 use select::predicate::{Name, Predicate, Text};
-use serde::export::Formatter;
+use std::fmt::Formatter;
 
-use crate::api_parser::tables::{DtoRow, DtoTable, DtoTables, MethodRow, MethodTable, MethodTables, Table};
+use crate::api_parser::diagnostics::Diagnostics;
+use crate::api_parser::tables::{DtoRow, DtoTable, DtoTables, MethodRow, MethodTable, MethodTables, Table, UnionTable, UnionTables};
 
 pub trait Scraper {
     fn get_dto_tables(&self) -> DtoTables;
 
     fn get_method_tables(&self) -> MethodTables;
+
+    fn get_union_tables(&self) -> UnionTables;
+
+    /// Get the h3 sections which contain tables, in the order they appear in the api doc.
+    fn get_categories(&self) -> Vec<String>;
 }
 
 pub type ScraperResult = Result<ScraperImpl, ScraperError>;
+type ScraperDiagnostics = Diagnostics<ScraperDiagnostic>;
+pub type LenientScraperResult = Result<(ScraperImpl, Vec<ScraperDiagnostic>), ScraperError>;
 
 #[derive(Debug)]
 pub struct ScraperImpl {
@@ -19,45 +27,122 @@ pub struct ScraperImpl {
 }
 
 impl ScraperImpl {
+    const H3: &'static str = "h3";
     const H4: &'static str = "h4";
+    const PARAGRAPH: &'static str = "p";
+    const UNORDERED_LIST: &'static str = "ul";
+    const LIST_ITEM: &'static str = "li";
     const TABLE: &'static str = "table";
     const TABLE_BODY: &'static str = "tbody";
     const TABLE_HEADER: &'static str = "th";
     const TABLE_ROW: &'static str = "tr";
     const TABLE_DATA: &'static str = "td";
-    const DTO_TABLE_COLUMNS: usize = 3;
-    const METHOD_TABLE_COLUMNS: usize = 4;
-
+    const TYPES_SECTION: &'static str = "Available types";
+    const METHODS_SECTION: &'static str = "Available methods";
+    const FIELD_LABEL: &'static str = "Field";
+    const PARAMETER_LABEL: &'static str = "Parameter";
+    const TYPE_LABEL: &'static str = "Type";
+    const REQUIRED_LABEL: &'static str = "Required";
+    const DESCRIPTION_LABEL: &'static str = "Description";
+    const SHOULD_BE_ONE_OF_STR: &'static str = "should be one of";
+    const FOLLOWING_STR: &'static str = "the following";
+    const TYPES_STR: &'static str = "types:";
+
+    /// Scrape the HTML and stop at the first error.
     pub fn from_html<R: std::io::Read>(api_html: R) -> ScraperResult {
+        let mut diagnostics = Diagnostics::strict();
+        Self::scrape(api_html, &mut diagnostics)
+    }
+
+    /// Scrape the HTML, but skip the tables, rows and headers which can't be read instead of
+    /// stopping. Each of them is reported with a diagnostic. Only an unreadable document is an error.
+    pub fn from_html_lenient<R: std::io::Read>(api_html: R) -> LenientScraperResult {
+        let mut diagnostics = Diagnostics::lenient();
+        let scraper = Self::scrape(api_html, &mut diagnostics)?;
+        Ok((scraper, diagnostics.collected))
+    }
+
+    fn scrape<R: std::io::Read>(api_html: R, diagnostics: &mut ScraperDiagnostics) -> ScraperResult {
         let mut tables = Vec::new();
         let document = Document::from_read(api_html)?;
+        let mut current_section = None;
         let mut current_table_name = None;
+        let mut current_description = String::new();
+        let mut current_list_items = Vec::new();
+        let mut current_header_has_table = false;
 
         for node in document.find(Self::searched_nodes_predicate()) {
             if let Some(name) = node.name() {
                 match name {
-                    Self::H4 => current_table_name = Some(Self::get_node_text(&node)?),
-                    Self::TABLE => tables.push(Self::extract_table_from_node(&node, &current_table_name)?),
+                    Self::H3 => {
+                        if !current_header_has_table {
+                            tables.extend(Self::create_table_without_rows(&current_table_name, &current_description, &current_list_items).map(|table| table.with_category(current_section.clone())))
+                        }
+                        current_section = diagnostics.handle(Self::get_node_text(&node), |error| ScraperDiagnostic::new(&None, None, error))?;
+                        current_table_name = None;
+                        current_header_has_table = false
+                    }
+                    Self::H4 => {
+                        if !current_header_has_table {
+                            tables.extend(Self::create_table_without_rows(&current_table_name, &current_description, &current_list_items).map(|table| table.with_category(current_section.clone())))
+                        }
+                        current_table_name = diagnostics.handle(Self::get_node_text(&node), |error| ScraperDiagnostic::new(&None, None, error))?;
+                        current_description.clear();
+                        current_list_items.clear();
+                        current_header_has_table = false
+                    }
+                    Self::PARAGRAPH => Self::append_paragraph(&mut current_description, &node),
+                    Self::UNORDERED_LIST if !current_header_has_table => current_list_items.extend(Self::get_list_items(&node)),
+                    Self::TABLE => {
+                        let table_result = Self::extract_table_from_node(&node, &current_section, &current_table_name, &current_description, diagnostics);
+                        tables.extend(diagnostics.handle(table_result, |error| ScraperDiagnostic::new(&current_table_name, None, error))?.flatten().map(|table| table.with_category(current_section.clone())));
+                        current_header_has_table = true
+                    }
                     _ => ()
                 }
             }
         }
 
+        if !current_header_has_table {
+            tables.extend(Self::create_table_without_rows(&current_table_name, &current_description, &current_list_items).map(|table| table.with_category(current_section.clone())))
+        }
+
         Ok(ScraperImpl {
             tables
         })
     }
 
     fn searched_nodes_predicate() -> impl Predicate {
-        Name(Self::H4).or(Name(Self::TABLE))
+        Name(Self::H3).or(Name(Self::H4)).or(Name(Self::TABLE)).or(Name(Self::PARAGRAPH)).or(Name(Self::UNORDERED_LIST))
+    }
+
+    /// Append the text of a paragraph to the description of the current h4 header.
+    /// Paragraphs are separated by a single whitespace.
+    fn append_paragraph(description: &mut String, paragraph_node: &Node) {
+        let paragraph_text = paragraph_node.text();
+
+        if paragraph_text.is_empty() {
+            return;
+        }
+
+        if !description.is_empty() {
+            description.push(' ')
+        }
+        description.push_str(paragraph_text.as_str())
+    }
+
+    fn get_list_items(list_node: &Node) -> Vec<String> {
+        list_node.find(Name(Self::LIST_ITEM))
+            .map(|list_item| String::from(list_item.text().trim()))
+            .filter(|list_item| !list_item.is_empty())
+            .collect()
     }
 
     fn get_node_text(node: &Node) -> Result<String, ScraperError> {
         let text_nodes: Vec<Node> = node.find(Text).collect();
-        let mut text_node_iter = text_nodes.iter();
         let mut node_text = String::new();
 
-        while let Some(text_node) = text_node_iter.next() {
+        for text_node in text_nodes.iter() {
             node_text.push_str(text_node.text().as_str())
         }
 
@@ -67,31 +152,84 @@ impl ScraperImpl {
         }
     }
 
-    fn get_table_content_type(table_node: &Node) -> Result<TableContentType, ScraperError> {
-        match table_node.find(Name(Self::TABLE_HEADER)).count() {
-            Self::DTO_TABLE_COLUMNS => Ok(TableContentType::DTO),
-            Self::METHOD_TABLE_COLUMNS => Ok(TableContentType::Method),
-            columns => Err(ScraperError::InvalidTableColumns { columns })
+    /// A h4 header without a table still introduces a DTO or method (like getMe or CallbackGame) if
+    /// its name is a single word. Methods start with a lowercase letter, DTOs with an uppercase one.
+    /// A DTO followed by a list (like InputMedia) is a union of the listed types, if its description
+    /// announces the list as the possible types. Otherwise the list is just part of the description.
+    /// Other headers (like "Formatting options") are just headers.
+    fn create_table_without_rows(table_name: &Option<String>, description: &str, list_items: &[String]) -> Option<Table> {
+        match table_name {
+            Some(name) if !Self::is_entity_name(name) => None,
+            Some(name) if name.starts_with(char::is_lowercase) => Some(Table::Method(MethodTable::new(name.clone(), String::from(description)))),
+            Some(name) if !list_items.is_empty() && Self::is_union_description(description) => Some(Table::Union(UnionTable::new(name.clone(), String::from(description), list_items.to_vec()))),
+            Some(name) => Some(Table::Dto(DtoTable::new(name.clone(), String::from(description)))),
+            None => None
         }
     }
 
-    fn extract_table_from_node(table_node: &Node, current_table_name: &Option<String>) -> Result<Table, ScraperError> {
-        let table_content_type = Self::get_table_content_type(table_node)?;
-        match (table_content_type, current_table_name) {
-            (TableContentType::DTO, Some(table_name)) => Ok(Self::extract_dto_table(table_name.clone(), table_node)?),
-            (TableContentType::Method, Some(table_name)) => Ok(Self::extract_method_table(table_name.clone(), table_node)?),
-            (_, None) => Err(ScraperError::TableWithoutHeader)
+    /// DTOs and methods are named with a single word, other headers (like "Formatting options") are not.
+    fn is_entity_name(name: &str) -> bool {
+        !name.contains(char::is_whitespace)
+    }
+
+    /// Unions are described with "It should be one of" or "... support the following 20 types:".
+    fn is_union_description(description: &str) -> bool {
+        let description = description.trim();
+        description.contains(Self::SHOULD_BE_ONE_OF_STR) || (description.contains(Self::FOLLOWING_STR) && description.ends_with(Self::TYPES_STR))
+    }
+
+    fn get_header_labels(table_node: &Node) -> Vec<String> {
+        table_node.find(Name(Self::TABLE_HEADER))
+            .map(|header| String::from(header.text().trim()))
+            .collect()
+    }
+
+    /// The labels of the table header decide first, wherever they are: a "Field" column for DTOs,
+    /// "Parameter" and "Required" columns for methods. If they don't, the h3 section is used,
+    /// and at last the name of the h4 header: methods start with a lowercase letter, DTOs with an uppercase one.
+    fn get_table_content_type(header_labels: &[String], section: &Option<String>, table_name: &str) -> TableContentType {
+        let has_label = |label: &str| header_labels.iter().any(|header_label| header_label == label);
+        let is_dto = has_label(Self::FIELD_LABEL);
+        let is_method = has_label(Self::PARAMETER_LABEL) && has_label(Self::REQUIRED_LABEL);
+
+        match (is_dto, is_method, section.as_deref()) {
+            (true, false, _) => TableContentType::DTO,
+            (false, true, _) => TableContentType::Method,
+            (_, _, Some(Self::TYPES_SECTION)) => TableContentType::DTO,
+            (_, _, Some(Self::METHODS_SECTION)) => TableContentType::Method,
+            _ if table_name.starts_with(char::is_lowercase) => TableContentType::Method,
+            _ => TableContentType::DTO
         }
     }
 
-    fn extract_dto_table(dto_name: String, table_node: &Node) -> Result<Table, ScraperError> {
-        let mut dto_table = DtoTable::new(dto_name.clone());
+    /// Tables below headers which don't name a DTO or method (like the formatting options) are ignored.
+    fn extract_table_from_node(table_node: &Node, current_section: &Option<String>, current_table_name: &Option<String>, current_description: &str, diagnostics: &mut ScraperDiagnostics) -> Result<Option<Table>, ScraperError> {
+        let table_name = match current_table_name {
+            Some(table_name) if Self::is_entity_name(table_name) => table_name,
+            Some(_) => return Ok(None),
+            None => return Err(ScraperError::TableWithoutHeader)
+        };
+        let header_labels = Self::get_header_labels(table_node);
+        let table_content_type = Self::get_table_content_type(&header_labels, current_section, table_name);
+        let columns = TableColumns::from_labels(&table_content_type, &header_labels, table_name)?;
+
+        let table = match table_content_type {
+            TableContentType::DTO => Self::extract_dto_table(table_name.clone(), String::from(current_description), table_node, &columns, diagnostics)?,
+            TableContentType::Method => Self::extract_method_table(table_name.clone(), String::from(current_description), table_node, &columns, diagnostics)?
+        };
+        Ok(Some(table))
+    }
+
+    fn extract_dto_table(dto_name: String, description: String, table_node: &Node, columns: &TableColumns, diagnostics: &mut ScraperDiagnostics) -> Result<Table, ScraperError> {
+        let mut dto_table = DtoTable::new(dto_name.clone(), description);
         let table_bodies = Self::get_table_body_nodes(table_node);
 
-        match table_bodies.get(0) {
+        match table_bodies.first() {
             Some(table_body) => {
-                for table_row in table_body.find(Name(Self::TABLE_ROW)) {
-                    dto_table.add_row(Self::extract_dto_row(&table_row)?);
+                for (row_index, table_row) in table_body.find(Name(Self::TABLE_ROW)).enumerate() {
+                    if let Some(row) = diagnostics.handle(Self::extract_dto_row(&table_row, columns), |error| ScraperDiagnostic::new(&Some(dto_name.clone()), Some(row_index), error))? {
+                        dto_table.add_row(row)
+                    }
                 }
             }
             None => return Err(ScraperError::MissingTableBody { table_name: dto_name })
@@ -100,11 +238,11 @@ impl ScraperImpl {
         Ok(Table::Dto(dto_table))
     }
 
-    fn extract_dto_row(table_row: &Node) -> Result<DtoRow, ScraperError> {
+    fn extract_dto_row(table_row: &Node, columns: &TableColumns) -> Result<DtoRow, ScraperError> {
         let data_nodes = Self::get_table_data_nodes(table_row);
-        let field_node_option = data_nodes.get(0);
-        let type_node_option = data_nodes.get(1);
-        let description_node_option = data_nodes.get(2);
+        let field_node_option = data_nodes.get(columns.name);
+        let type_node_option = data_nodes.get(columns.type_column);
+        let description_node_option = data_nodes.get(columns.description);
 
         match (field_node_option, type_node_option, description_node_option) {
             (Some(field_node), Some(type_node), Some(description_node)) => {
@@ -117,14 +255,16 @@ impl ScraperImpl {
         }
     }
 
-    fn extract_method_table(method_name: String, table_node: &Node) -> Result<Table, ScraperError> {
-        let mut method_table = MethodTable::new(method_name.clone());
+    fn extract_method_table(method_name: String, description: String, table_node: &Node, columns: &TableColumns, diagnostics: &mut ScraperDiagnostics) -> Result<Table, ScraperError> {
+        let mut method_table = MethodTable::new(method_name.clone(), description);
         let table_bodies = Self::get_table_body_nodes(table_node);
 
-        match table_bodies.get(0) {
+        match table_bodies.first() {
             Some(table_body) => {
-                for table_row in table_body.find(Name(Self::TABLE_ROW)) {
-                    method_table.add_row(Self::extract_method_row(&table_row)?);
+                for (row_index, table_row) in table_body.find(Name(Self::TABLE_ROW)).enumerate() {
+                    if let Some(row) = diagnostics.handle(Self::extract_method_row(&table_row, columns), |error| ScraperDiagnostic::new(&Some(method_name.clone()), Some(row_index), error))? {
+                        method_table.add_row(row)
+                    }
                 }
             }
             None => return Err(ScraperError::MissingTableBody { table_name: method_name })
@@ -133,12 +273,12 @@ impl ScraperImpl {
         Ok(Table::Method(method_table))
     }
 
-    fn extract_method_row(table_row: &Node) -> Result<MethodRow, ScraperError> {
+    fn extract_method_row(table_row: &Node, columns: &TableColumns) -> Result<MethodRow, ScraperError> {
         let data_nodes = Self::get_table_data_nodes(table_row);
-        let parameter_node_option = data_nodes.get(0);
-        let type_node_option = data_nodes.get(1);
-        let required_node_option = data_nodes.get(2);
-        let description_node_option = data_nodes.get(3);
+        let parameter_node_option = data_nodes.get(columns.name);
+        let type_node_option = data_nodes.get(columns.type_column);
+        let required_node_option = columns.required.and_then(|required| data_nodes.get(required));
+        let description_node_option = data_nodes.get(columns.description);
 
         match (parameter_node_option, type_node_option, required_node_option, description_node_option) {
             (Some(parameter_node), Some(type_node), Some(required_node), Some(description_node)) => {
@@ -161,11 +301,77 @@ impl ScraperImpl {
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 enum TableContentType {
     DTO,
     Method,
 }
 
+/// The positions of the columns in a table, found by the labels of its header.
+/// This way additional or reordered columns don't break the scraping.
+struct TableColumns {
+    name: usize,
+    type_column: usize,
+    required: Option<usize>,
+    description: usize,
+}
+
+impl TableColumns {
+    /// A table without header labels is expected to have the columns in their usual order.
+    fn from_labels(table_content_type: &TableContentType, header_labels: &[String], table_name: &str) -> Result<Self, ScraperError> {
+        if header_labels.is_empty() {
+            return Ok(match table_content_type {
+                TableContentType::DTO => TableColumns { name: 0, type_column: 1, required: None, description: 2 },
+                TableContentType::Method => TableColumns { name: 0, type_column: 1, required: Some(2), description: 3 }
+            });
+        }
+
+        let find_column = |label: &'static str| header_labels.iter()
+            .position(|header_label| header_label == label)
+            .ok_or_else(|| ScraperError::MissingTableColumn { table_name: String::from(table_name), column: String::from(label) });
+
+        match table_content_type {
+            TableContentType::DTO => Ok(TableColumns {
+                name: find_column(ScraperImpl::FIELD_LABEL)?,
+                type_column: find_column(ScraperImpl::TYPE_LABEL)?,
+                required: None,
+                description: find_column(ScraperImpl::DESCRIPTION_LABEL)?,
+            }),
+            TableContentType::Method => Ok(TableColumns {
+                name: find_column(ScraperImpl::PARAMETER_LABEL)?,
+                type_column: find_column(ScraperImpl::TYPE_LABEL)?,
+                required: Some(find_column(ScraperImpl::REQUIRED_LABEL)?),
+                description: find_column(ScraperImpl::DESCRIPTION_LABEL)?,
+            })
+        }
+    }
+}
+
+/// A part of the HTML that was skipped in lenient mode. Contains the name of the h4 header
+/// and the index of the row in the table body, if known.
+#[derive(Debug, PartialEq)]
+pub struct ScraperDiagnostic {
+    pub table_name: Option<String>,
+    pub row_index: Option<usize>,
+    pub error: ScraperError,
+}
+
+impl ScraperDiagnostic {
+    fn new(table_name: &Option<String>, row_index: Option<usize>, error: ScraperError) -> Self {
+        ScraperDiagnostic { table_name: table_name.clone(), row_index, error }
+    }
+}
+
+impl std::fmt::Display for ScraperDiagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.table_name, self.row_index) {
+            (Some(table_name), Some(row_index)) => write!(f, "Skipped row {} of {}: {}", row_index, table_name, self.error),
+            (Some(table_name), None) => write!(f, "Skipped the table of {}: {}", table_name, self.error),
+            (None, _) => write!(f, "Skipped a table or header: {}", self.error)
+        }
+    }
+}
+
 impl Scraper for ScraperImpl {
     fn get_dto_tables(&self) -> DtoTables {
         self.tables
@@ -190,6 +396,31 @@ impl Scraper for ScraperImpl {
                 tables
             })
     }
+
+    fn get_union_tables(&self) -> UnionTables {
+        self.tables
+            .clone()
+            .into_iter()
+            .fold(vec![], |mut tables, table| {
+                if let Table::Union(union_table) = table {
+                    tables.push(union_table)
+                }
+                tables
+            })
+    }
+
+    fn get_categories(&self) -> Vec<String> {
+        self.tables
+            .iter()
+            .fold(vec![], |mut categories, table| {
+                if let Some(category) = table.category() {
+                    if !categories.contains(category) {
+                        categories.push(category.clone())
+                    }
+                }
+                categories
+            })
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -197,7 +428,7 @@ pub enum ScraperError {
     DocumentError,
     EmptyTextNode,
     TableWithoutHeader,
-    InvalidTableColumns { columns: usize },
+    MissingTableColumn { table_name: String, column: String },
     InvalidTableRow,
     MissingTableBody { table_name: String },
 }
@@ -216,7 +447,7 @@ impl std::fmt::Display for ScraperError {
             ScraperError::DocumentError => writeln!(f, "The HTML could not be read!"),
             ScraperError::EmptyTextNode => writeln!(f, "A node did not contain any text!"),
             ScraperError::TableWithoutHeader => writeln!(f, "A table does not have a h4 header! This may indicate a changed html format."),
-            ScraperError::InvalidTableColumns { columns } => writeln!(f, "A table has a invalid amount of columns: {}. This may indicate a changed html format.", columns),
+            ScraperError::MissingTableColumn { table_name, column } => writeln!(f, "The table {} has no column {}. This may indicate a changed html format.", table_name, column),
             ScraperError::InvalidTableRow => writeln!(f, "A table has an invalid row."),
             ScraperError::MissingTableBody { table_name } => writeln!(f, "The table {} has no body!", table_name),
         }
@@ -225,10 +456,10 @@ impl std::fmt::Display for ScraperError {
 
 #[cfg(test)]
 mod tests {
-    use crate::api_parser::scraper::{Scraper, ScraperError, ScraperImpl};
-    use crate::api_parser::tables::{DtoRow, DtoTable, DtoTables, MethodRow, MethodTable, MethodTables};
+    use crate::api_parser::scraper::{Scraper, ScraperDiagnostic, ScraperError, ScraperImpl};
+    use crate::api_parser::tables::{DtoRow, DtoTable, DtoTables, MethodRow, MethodTable, MethodTables, UnionTable, UnionTables};
 
-    const TABLE_HTML: &'static str = r#"
+    const TABLE_HTML: &str = r#"
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>FooDto</h4>
     <p>A nice Dto.</p>
     <table>
@@ -253,7 +484,9 @@ mod tests {
         </tbody>
     </table>
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>BarDto</h4>
-    <p>Just a header.</p>
+    <p>A Dto without fields.</p>
+    <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>Just a header</h4>
+    <p>Not a Dto.</p>
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>BazDto</h4>
     <p>Another nice Dto.</p>
     <table>
@@ -274,6 +507,7 @@ mod tests {
     </table>
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>FooMethod</h4>
     <p>A nice method.</p>
+    <p>On success, a <a href=#foo>Foo</a> is returned.</p>
     <table>
         <thead>
             <tr>
@@ -292,9 +526,17 @@ mod tests {
             </tr>
         </tbody>
     </table>
+    <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>barMethod</h4>
+    <p>A method without parameters. Returns <a href=#bar>Bar</a> on success.</p>
+    <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>FooUnion</h4>
+    <p>A nice union. It should be one of</p>
+    <ul>
+        <li><a href=#foodto>FooDto</a></li>
+        <li><a href=#bazdto>BazDto</a></li>
+    </ul>
     "#;
 
-    const INVALID_TABLE_COLUMNS: &'static str = r#"
+    const INVALID_TABLE_COLUMNS: &str = r#"
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>FooDto</h4>
     <table>
         <thead>
@@ -312,7 +554,7 @@ mod tests {
     </table>
     "#;
 
-    const INVALID_TABLE_ROW: &'static str = r#"
+    const INVALID_TABLE_ROW: &str = r#"
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>FooDto</h4>
     <table>
         <thead>
@@ -330,7 +572,7 @@ mod tests {
     </table>
     "#;
 
-    const INVALID_TABLE_MISSING_BODY: &'static str = r#"
+    const INVALID_TABLE_MISSING_BODY: &str = r#"
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>FooDto</h4>
     <table>
         <thead>
@@ -343,7 +585,7 @@ mod tests {
     </table>
     "#;
 
-    const INVALID_HTML_EMPTY_TEXT_NODE: &'static str = r#"
+    const INVALID_HTML_EMPTY_TEXT_NODE: &str = r#"
     <h4><a class="anchor" name="update"><i class="anchor-icon"></i></a>FooDto</h4>
     <table>
         <thead>
@@ -363,7 +605,7 @@ mod tests {
     </table>
     "#;
 
-    const INVALID_TABLE_NO_HEADER: &'static str = r#"
+    const INVALID_TABLE_NO_HEADER: &str = r#"
     <table>
         <thead>
             <tr>
@@ -387,11 +629,64 @@ mod tests {
     </table>
     "#;
 
+    const TABLE_HTML_CHANGED_FORMAT: &str = r#"
+    <h3><a class="anchor" name="available-methods"><i class="anchor-icon"></i></a>Available methods</h3>
+    <h4><a class="anchor" name="formatting-options"><i class="anchor-icon"></i></a>Formatting options</h4>
+    <table>
+        <thead>
+            <tr>
+                <th>Entity</th>
+                <th>Example</th>
+            </tr>
+        </thead>
+        <tbody>
+            <tr>
+                <td>bold</td>
+                <td>*bold*</td>
+            </tr>
+        </tbody>
+    </table>
+    <h4><a class="anchor" name="foomethod"><i class="anchor-icon"></i></a>FooMethod</h4>
+    <table>
+        <tbody>
+            <tr>
+                <td>foo</td>
+                <td>Foo</td>
+                <td>Optional</td>
+                <td>A foo parameter.</td>
+            </tr>
+        </tbody>
+    </table>
+    <h3><a class="anchor" name="available-types"><i class="anchor-icon"></i></a>Available types</h3>
+    <h4><a class="anchor" name="foodto"><i class="anchor-icon"></i></a>FooDto</h4>
+    <table>
+        <thead>
+            <tr>
+                <th>Field</th>
+                <th>Since</th>
+                <th>Description</th>
+                <th>Type</th>
+            </tr>
+        </thead>
+        <tbody>
+            <tr>
+                <td>foo</td>
+                <td>5.0</td>
+                <td>A foo field.</td>
+                <td>Foo</td>
+            </tr>
+        </tbody>
+    </table>
+    "#;
+
     /// The html string used for this test contains
-    /// - two Dtos, FooDto and BazDto
-    /// - one method
-    /// - three h4 headers (so one that does not introduce a Dto)
+    /// - three Dtos, FooDto, BarDto (without a table) and BazDto
+    /// - two methods, FooMethod and barMethod (without a table)
+    /// - one union, FooUnion, with a list of its types
+    /// - one h4 header with multiple words, which does not introduce a Dto
     /// - additional elements between header and table
+    /// - paragraphs between header and table which form the description
+    ///
     /// Some table cells also contain additional elements like <em>.
     #[test]
     fn success_extract_dto_tables() {
@@ -399,49 +694,58 @@ mod tests {
 
         let dto_tables = scraper.get_dto_tables();
         let method_tables = scraper.get_method_tables();
+        let union_tables = scraper.get_union_tables();
 
         assert_eq!(dto_tables, create_expected_dto_tables());
-        assert_eq!(method_tables, create_expected_method_tables())
+        assert_eq!(method_tables, create_expected_method_tables());
+        assert_eq!(union_tables, create_expected_union_tables())
     }
 
     fn create_expected_dto_tables() -> DtoTables {
-        let mut tables = Vec::new();
-        tables.push(create_first_dto_table());
-        tables.push(create_second_dto_table());
-        tables
+        vec![
+            create_first_dto_table(),
+            DtoTable::new(String::from("BarDto"), String::from("A Dto without fields.")),
+            create_second_dto_table()
+        ]
     }
 
     fn create_first_dto_table() -> DtoTable {
-        let mut table = DtoTable::new(String::from("FooDto"));
+        let mut table = DtoTable::new(String::from("FooDto"), String::from("A nice Dto."));
         table.add_row(DtoRow::new(String::from("foos"), String::from("Array of Foo"), String::from("Optional. foo bar baz.")));
         table.add_row(DtoRow::new(String::from("bar"), String::from("Bar"), String::from("Bar bar bar.")));
         table
     }
 
     fn create_second_dto_table() -> DtoTable {
-        let mut table = DtoTable::new(String::from("BazDto"));
+        let mut table = DtoTable::new(String::from("BazDto"), String::from("Another nice Dto."));
         table.add_row(DtoRow::new(String::from("baz"), String::from("Baz"), String::from("Baz baz baz.")));
         table
     }
 
     fn create_expected_method_tables() -> MethodTables {
-        let mut tables = Vec::new();
-        tables.push(create_method_table());
-        tables
+        vec![
+            create_method_table(),
+            MethodTable::new(String::from("barMethod"), String::from("A method without parameters. Returns Bar on success."))
+        ]
     }
 
     fn create_method_table() -> MethodTable {
-        let mut table = MethodTable::new(String::from("FooMethod"));
+        let mut table = MethodTable::new(String::from("FooMethod"), String::from("A nice method. On success, a Foo is returned."));
         table.add_row(MethodRow::new(String::from("foo"), String::from("Foo"), String::from("Optional"), String::from("A foo parameter.")));
         table
     }
 
+    fn create_expected_union_tables() -> UnionTables {
+        let members = vec![String::from("FooDto"), String::from("BazDto")];
+        vec![UnionTable::new(String::from("FooUnion"), String::from("A nice union. It should be one of"), members)]
+    }
+
     #[test]
     fn failure_invalid_table_columns() {
         let scraper_result = ScraperImpl::from_html(INVALID_TABLE_COLUMNS.as_bytes());
 
         match scraper_result {
-            Err(err) => assert_eq!(err, ScraperError::InvalidTableColumns { columns: 2 }),
+            Err(err) => assert_eq!(err, ScraperError::MissingTableColumn { table_name: String::from("FooDto"), column: String::from("Type") }),
             Ok(_) => panic!("Result was not an error!")
         }
     }
@@ -485,4 +789,84 @@ mod tests {
             Ok(_) => panic!("Result was not an error!")
         }
     }
+
+    #[test]
+    fn success_lenient_invalid_table_row() {
+        let (scraper, diagnostics) = ScraperImpl::from_html_lenient(INVALID_TABLE_ROW.as_bytes()).unwrap();
+
+        let expected = ScraperDiagnostic { table_name: Some(String::from("FooDto")), row_index: Some(0), error: ScraperError::InvalidTableRow };
+        assert_eq!(diagnostics, vec![expected]);
+        assert_eq!(scraper.get_dto_tables(), vec![DtoTable::new(String::from("FooDto"), String::new())])
+    }
+
+    #[test]
+    fn success_lenient_invalid_table_columns() {
+        let html = format!("{}{}", INVALID_TABLE_COLUMNS, TABLE_HTML);
+
+        let (scraper, diagnostics) = ScraperImpl::from_html_lenient(html.as_bytes()).unwrap();
+
+        let expected = ScraperDiagnostic { table_name: Some(String::from("FooDto")), row_index: None, error: ScraperError::MissingTableColumn { table_name: String::from("FooDto"), column: String::from("Type") } };
+        assert_eq!(diagnostics, vec![expected]);
+        assert_eq!(scraper.get_dto_tables(), create_expected_dto_tables())
+    }
+
+    const TABLE_HTML_PREPENDED_COLUMN: &str = r#"
+    <h3><a class="anchor" name="available-types"><i class="anchor-icon"></i></a>Available types</h3>
+    <h4><a class="anchor" name="sendfoo"><i class="anchor-icon"></i></a>sendFoo</h4>
+    <table>
+        <thead>
+            <tr>
+                <th>Since</th>
+                <th>Parameter</th>
+                <th>Type</th>
+                <th>Required</th>
+                <th>Description</th>
+            </tr>
+        </thead>
+        <tbody>
+            <tr>
+                <td>5.0</td>
+                <td>foo</td>
+                <td>Foo</td>
+                <td>Yes</td>
+                <td>A foo parameter.</td>
+            </tr>
+        </tbody>
+    </table>
+    "#;
+
+    /// The html string used for this test contains
+    /// - a table below a header with multiple words, which is ignored
+    /// - a method table without header labels, which is classified by its section
+    /// - a DTO table with an additional column and reordered columns
+    ///
+    /// The tables belong to the category of their h3 section.
+    #[test]
+    fn success_extract_tables_changed_format() {
+        let scraper = ScraperImpl::from_html(TABLE_HTML_CHANGED_FORMAT.as_bytes()).unwrap();
+
+        let mut method_table = MethodTable::new(String::from("FooMethod"), String::new());
+        method_table.category = Some(String::from("Available methods"));
+        method_table.add_row(MethodRow::new(String::from("foo"), String::from("Foo"), String::from("Optional"), String::from("A foo parameter.")));
+        let mut dto_table = DtoTable::new(String::from("FooDto"), String::new());
+        dto_table.category = Some(String::from("Available types"));
+        dto_table.add_row(DtoRow::new(String::from("foo"), String::from("Foo"), String::from("A foo field.")));
+
+        assert_eq!(scraper.get_method_tables(), vec![method_table]);
+        assert_eq!(scraper.get_dto_tables(), vec![dto_table]);
+        assert_eq!(scraper.get_categories(), vec![String::from("Available methods"), String::from("Available types")])
+    }
+
+    /// The labels decide even if they don't start the header and the table is in the wrong section.
+    #[test]
+    fn success_extract_method_table_with_prepended_column() {
+        let scraper = ScraperImpl::from_html(TABLE_HTML_PREPENDED_COLUMN.as_bytes()).unwrap();
+
+        let mut method_table = MethodTable::new(String::from("sendFoo"), String::new());
+        method_table.category = Some(String::from("Available types"));
+        method_table.add_row(MethodRow::new(String::from("foo"), String::from("Foo"), String::from("Yes"), String::from("A foo parameter.")));
+
+        assert_eq!(scraper.get_method_tables(), vec![method_table]);
+        assert!(scraper.get_dto_tables().is_empty())
+    }
 }
\ No newline at end of file
@@ -2,21 +2,47 @@
 pub enum Table {
     Dto(DtoTable),
     Method(MethodTable),
+    Union(UnionTable),
+}
+
+impl Table {
+    /// Set the h3 section (like "Stickers") the table belongs to.
+    pub fn with_category(mut self, category: Option<String>) -> Self {
+        match &mut self {
+            Table::Dto(table) => table.category = category,
+            Table::Method(table) => table.category = category,
+            Table::Union(table) => table.category = category,
+        }
+        self
+    }
+
+    pub fn category(&self) -> Option<&String> {
+        match self {
+            Table::Dto(table) => table.category.as_ref(),
+            Table::Method(table) => table.category.as_ref(),
+            Table::Union(table) => table.category.as_ref(),
+        }
+    }
 }
 
 pub type DtoTables = Vec<DtoTable>;
 pub type MethodTables = Vec<MethodTable>;
+pub type UnionTables = Vec<UnionTable>;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct DtoTable {
     pub name: String,
+    pub description: String,
+    pub category: Option<String>,
     pub rows: Vec<DtoRow>,
 }
 
 impl DtoTable {
-    pub fn new(dto_name: String) -> Self {
+    pub fn new(dto_name: String, description: String) -> Self {
         DtoTable {
             name: dto_name,
+            description,
+            category: None,
             rows: Vec::new(),
         }
     }
@@ -46,13 +72,17 @@ impl DtoRow {
 #[derive(Clone, Debug, PartialEq)]
 pub struct MethodTable {
     pub name: String,
+    pub description: String,
+    pub category: Option<String>,
     pub rows: Vec<MethodRow>,
 }
 
 impl MethodTable {
-    pub fn new(method_name: String) -> Self {
+    pub fn new(method_name: String, description: String) -> Self {
         MethodTable {
             name: method_name,
+            description,
+            category: None,
             rows: Vec::new(),
         }
     }
@@ -79,4 +109,25 @@ impl MethodRow {
             description_string,
         }
     }
+}
+
+/// A type which is one of several other types. It is not defined by a table,
+/// but by a list of the possible types.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnionTable {
+    pub name: String,
+    pub description: String,
+    pub category: Option<String>,
+    pub members: Vec<String>,
+}
+
+impl UnionTable {
+    pub fn new(union_name: String, description: String, members: Vec<String>) -> Self {
+        UnionTable {
+            name: union_name,
+            description,
+            category: None,
+            members,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,263 @@
+use std::fmt::Formatter;
+
+use crate::raw_api::type_descriptor::TypeDescriptor;
+
+/// Parses the type expressions used in the api doc into a TypeDescriptor.
+///
+/// The grammar looks like this:
+/// - expression = single (("or" | "and") single)*
+/// - single = "Array" "of" expression | primitive | dto name
+/// - primitive = "Integer" | "String" | "Boolean" | "True" | "Float" ["number"]
+///
+/// A dto name is a single word starting with an uppercase letter. Everything
+/// else is reported as an error instead of being guessed.
+pub struct TypeExpressionParser {
+    expression: String,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl TypeExpressionParser {
+    const INTEGER_STR: &'static str = "Integer";
+    const STRING_STR: &'static str = "String";
+    const BOOLEAN_STR: &'static str = "Boolean";
+    const TRUE_STR: &'static str = "True";
+    const FLOAT_STR: &'static str = "Float";
+
+    pub fn parse(expression: &str) -> Result<TypeDescriptor, TypeExpressionError> {
+        let mut parser = TypeExpressionParser {
+            expression: String::from(expression),
+            tokens: expression.split_whitespace().map(Token::from).collect(),
+            position: 0,
+        };
+
+        if parser.tokens.is_empty() {
+            return Err(TypeExpressionError::EmptyExpression);
+        }
+
+        let type_descriptor = parser.parse_expression()?;
+
+        match parser.next_token() {
+            Some(token) => Err(parser.unexpected_token(token)),
+            None => Ok(type_descriptor)
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<TypeDescriptor, TypeExpressionError> {
+        let mut types = vec![self.parse_single()?];
+
+        while let Some(Token::Or) | Some(Token::And) = self.peek_token() {
+            self.next_token();
+            types.push(self.parse_single()?)
+        }
+
+        match types.len() {
+            1 => Ok(types.remove(0)),
+            _ => Ok(TypeDescriptor::OneOf(types))
+        }
+    }
+
+    fn parse_single(&mut self) -> Result<TypeDescriptor, TypeExpressionError> {
+        match self.next_token() {
+            Some(Token::Array) => {
+                self.expect_token(Token::Of)?;
+                Ok(TypeDescriptor::ArrayOf(Box::new(self.parse_expression()?)))
+            }
+            Some(Token::Word(word)) => self.parse_word(word),
+            Some(token) => Err(self.unexpected_token(token)),
+            None => Err(self.unexpected_end())
+        }
+    }
+
+    fn parse_word(&mut self, word: String) -> Result<TypeDescriptor, TypeExpressionError> {
+        match word.as_str() {
+            Self::INTEGER_STR => Ok(TypeDescriptor::Integer),
+            Self::STRING_STR => Ok(TypeDescriptor::String),
+            Self::BOOLEAN_STR => Ok(TypeDescriptor::Boolean),
+            Self::TRUE_STR => Ok(TypeDescriptor::True),
+            Self::FLOAT_STR => {
+                if let Some(Token::Number) = self.peek_token() {
+                    self.next_token();
+                }
+                Ok(TypeDescriptor::Float)
+            }
+            _ if Self::is_dto_name(&word) => Ok(TypeDescriptor::DTO(word)),
+            _ => Err(self.unexpected_token(Token::Word(word)))
+        }
+    }
+
+    fn is_dto_name(word: &str) -> bool {
+        word.starts_with(char::is_uppercase) && word.chars().all(char::is_alphanumeric)
+    }
+
+    fn expect_token(&mut self, expected: Token) -> Result<(), TypeExpressionError> {
+        match self.next_token() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(self.unexpected_token(token)),
+            None => Err(self.unexpected_end())
+        }
+    }
+
+    fn peek_token(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn unexpected_token(&self, token: Token) -> TypeExpressionError {
+        TypeExpressionError::UnexpectedToken { expression: self.expression.clone(), token: token.to_string() }
+    }
+
+    fn unexpected_end(&self) -> TypeExpressionError {
+        TypeExpressionError::UnexpectedEnd { expression: self.expression.clone() }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Array,
+    Of,
+    Or,
+    And,
+    Number,
+    Word(String),
+}
+
+impl From<&str> for Token {
+    fn from(word: &str) -> Self {
+        match word {
+            "Array" => Token::Array,
+            "of" => Token::Of,
+            "or" => Token::Or,
+            "and" => Token::And,
+            "number" => Token::Number,
+            _ => Token::Word(String::from(word))
+        }
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Array => write!(f, "Array"),
+            Token::Of => write!(f, "of"),
+            Token::Or => write!(f, "or"),
+            Token::And => write!(f, "and"),
+            Token::Number => write!(f, "number"),
+            Token::Word(word) => write!(f, "{}", word),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TypeExpressionError {
+    EmptyExpression,
+    UnexpectedToken { expression: String, token: String },
+    UnexpectedEnd { expression: String },
+}
+
+impl std::error::Error for TypeExpressionError {}
+
+impl std::fmt::Display for TypeExpressionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeExpressionError::EmptyExpression => writeln!(f, "A type expression is empty!"),
+            TypeExpressionError::UnexpectedToken { expression, token } => writeln!(f, "The type expression \"{}\" contains the unexpected token \"{}\".", expression, token),
+            TypeExpressionError::UnexpectedEnd { expression } => writeln!(f, "The type expression \"{}\" ended unexpectedly.", expression),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_parser::type_expression::{TypeExpressionError, TypeExpressionParser};
+    use crate::raw_api::type_descriptor::TypeDescriptor;
+
+    fn dto(name: &str) -> TypeDescriptor {
+        TypeDescriptor::DTO(String::from(name))
+    }
+
+    fn array_of(type_descriptor: TypeDescriptor) -> TypeDescriptor {
+        TypeDescriptor::ArrayOf(Box::new(type_descriptor))
+    }
+
+    #[test]
+    fn success_primitives() {
+        let input_expected = vec![
+            ("Integer", TypeDescriptor::Integer),
+            ("String", TypeDescriptor::String),
+            ("Boolean", TypeDescriptor::Boolean),
+            ("True", TypeDescriptor::True),
+            ("Float", TypeDescriptor::Float),
+            ("Float number", TypeDescriptor::Float)
+        ];
+
+        input_expected.into_iter().for_each(|(input, expected)| assert_eq!(TypeExpressionParser::parse(input), Ok(expected)));
+    }
+
+    #[test]
+    fn success_dto() {
+        assert_eq!(TypeExpressionParser::parse("InlineKeyboardMarkup"), Ok(dto("InlineKeyboardMarkup")))
+    }
+
+    #[test]
+    fn success_surrounding_whitespace() {
+        assert_eq!(TypeExpressionParser::parse("  Array of\n PhotoSize "), Ok(array_of(dto("PhotoSize"))))
+    }
+
+    #[test]
+    fn success_nested_array() {
+        assert_eq!(TypeExpressionParser::parse("Array of Array of PhotoSize"), Ok(array_of(array_of(dto("PhotoSize")))))
+    }
+
+    #[test]
+    fn success_one_of() {
+        let expected = TypeDescriptor::OneOf(vec![dto("InlineKeyboardMarkup"), dto("ReplyKeyboardMarkup"), dto("ReplyKeyboardRemove"), dto("ForceReply")]);
+
+        assert_eq!(TypeExpressionParser::parse("InlineKeyboardMarkup or ReplyKeyboardMarkup or ReplyKeyboardRemove or ForceReply"), Ok(expected))
+    }
+
+    #[test]
+    fn success_array_of_one_of() {
+        let expected = array_of(TypeDescriptor::OneOf(vec![dto("InputMediaPhoto"), dto("InputMediaVideo")]));
+
+        assert_eq!(TypeExpressionParser::parse("Array of InputMediaPhoto and InputMediaVideo"), Ok(expected))
+    }
+
+    #[test]
+    fn failure_empty_expression() {
+        assert_eq!(TypeExpressionParser::parse(" "), Err(TypeExpressionError::EmptyExpression))
+    }
+
+    #[test]
+    fn failure_lowercase_word() {
+        let expected = TypeExpressionError::UnexpectedToken { expression: String::from("Foo bar"), token: String::from("bar") };
+
+        assert_eq!(TypeExpressionParser::parse("Foo bar"), Err(expected))
+    }
+
+    #[test]
+    fn failure_missing_of() {
+        let expected = TypeExpressionError::UnexpectedToken { expression: String::from("Array Foo"), token: String::from("Foo") };
+
+        assert_eq!(TypeExpressionParser::parse("Array Foo"), Err(expected))
+    }
+
+    #[test]
+    fn failure_unexpected_end() {
+        let expected = TypeExpressionError::UnexpectedEnd { expression: String::from("Integer or") };
+
+        assert_eq!(TypeExpressionParser::parse("Integer or"), Err(expected))
+    }
+
+    #[test]
+    fn failure_invalid_dto_name() {
+        let expected = TypeExpressionError::UnexpectedToken { expression: String::from("Foo-Bar"), token: String::from("Foo-Bar") };
+
+        assert_eq!(TypeExpressionParser::parse("Foo-Bar"), Err(expected))
+    }
+}
\ No newline at end of file
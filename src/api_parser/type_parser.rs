@@ -1,56 +1,148 @@
+use crate::api_parser::type_expression::{TypeExpressionError, TypeExpressionParser};
+use crate::raw_api::requirement::Requirement;
 use crate::raw_api::type_descriptor::TypeDescriptor;
 
 pub trait TypeParser {
-    fn parse_field_type(&self, type_string: String, description_string: String) -> TypeDescriptor;
+    fn parse_field_type(&self, type_string: String, description_string: String) -> Result<TypeDescriptor, TypeExpressionError>;
 
-    fn parse_parameter_type(&self, type_string: String, required_string: String) -> TypeDescriptor;
+    fn parse_parameter_type(&self, type_string: String, description_string: String, requirement: &Requirement) -> Result<TypeDescriptor, TypeExpressionError>;
+
+    fn parse_requirement(&self, required_string: String) -> Requirement;
+
+    fn parse_return_type(&self, description_string: String) -> Result<Option<TypeDescriptor>, TypeExpressionError>;
 }
 
 pub struct TypeParserImpl;
 
 impl TypeParserImpl {
-    const INTEGER_STR: &'static str = "Integer";
-    const STRING_STR: &'static str = "String";
-    const INTEGER_OR_STRING_STR: &'static str = "Integer or String";
-    const BOOLEAN_STR: &'static str = "Boolean";
-    const ARRAY_OF_STR: &'static str = "Arrayof";
     const OPTIONAL_STR: &'static str = "Optional";
+    const YES_STR: &'static str = "Yes";
+    const REQUIRED_STR: &'static str = "Required";
+    const INT_STR: &'static str = "Int";
+    const RETURN_STR: &'static str = "return";
+    const ON_SUCCESS_STR: &'static str = "On success";
+    const RETURNS_STR: &'static str = "Returns";
+    const ARRAY_STR: &'static str = "array";
+    const OF_STR: &'static str = "of";
+    const OBJECTS_STR: &'static str = "objects";
+    const MORE_THAN_32_BITS_STRS: [&'static str; 2] = ["greater than 32 bits", "more than 32 significant bits"];
 
     fn field_is_optional_by_description(&self, description_string: String) -> bool {
         self.trim_whitespace(description_string).starts_with(Self::OPTIONAL_STR)
     }
 
-    fn create_base_field_type_from_string(&self, type_string: String) -> TypeDescriptor {
-        match type_string.as_str() {
-            Self::INTEGER_STR => TypeDescriptor::Integer,
-            Self::STRING_STR => TypeDescriptor::String,
-            Self::BOOLEAN_STR => TypeDescriptor::Boolean,
-            _ => {
-                let trimmed = self.trim_whitespace(type_string.clone());
-
-                if trimmed.starts_with(Self::ARRAY_OF_STR) {
-                    let array_type = String::from(&trimmed.as_str()[Self::ARRAY_OF_STR.len()..trimmed.len()]);
-                    TypeDescriptor::ArrayOf(Box::new(self.create_base_field_type_from_string(array_type)))
-                } else {
-                    TypeDescriptor::DTO(trimmed)
-                }
+    /// Some integers are described as possibly having more than 32 bits, like the id of a chat.
+    /// This also applies to integers inside of arrays and OneOfs, like a chat_id that is an Integer or String.
+    fn widen_integer(&self, base_type: TypeDescriptor, description_string: &str) -> TypeDescriptor {
+        match Self::MORE_THAN_32_BITS_STRS.iter().any(|bits_str| description_string.contains(bits_str)) {
+            true => Self::to_integer64(base_type),
+            false => base_type
+        }
+    }
+
+    fn to_integer64(base_type: TypeDescriptor) -> TypeDescriptor {
+        match base_type {
+            TypeDescriptor::Integer => TypeDescriptor::Integer64,
+            TypeDescriptor::ArrayOf(array_type) => TypeDescriptor::ArrayOf(Box::new(Self::to_integer64(*array_type))),
+            TypeDescriptor::Optional(optional_type) => TypeDescriptor::Optional(Box::new(Self::to_integer64(*optional_type))),
+            TypeDescriptor::OneOf(one_of_types) => TypeDescriptor::OneOf(one_of_types.into_iter().map(Self::to_integer64).collect()),
+            _ => base_type
+        }
+    }
+
+    fn wrap_optional(&self, base_type: TypeDescriptor, optional: bool) -> TypeDescriptor {
+        match optional {
+            true => TypeDescriptor::Optional(Box::new(base_type)),
+            false => base_type
+        }
+    }
+
+    /// Split a description into sentences. A sentence ends with a dot followed by a whitespace,
+    /// an uppercase letter (the HTML sometimes omits the space between sentences) or the end of the text.
+    fn split_sentences(&self, description_string: &str) -> Vec<String> {
+        let mut sentences = Vec::new();
+        let mut current_sentence = String::new();
+        let mut chars = description_string.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('.', None) => (),
+                ('.', Some(next)) if next.is_whitespace() || next.is_uppercase() => sentences.push(std::mem::take(&mut current_sentence)),
+                _ => current_sentence.push(c)
             }
         }
+        sentences.push(current_sentence);
+
+        sentences.into_iter()
+            .map(|sentence| String::from(sentence.trim()))
+            .filter(|sentence| !sentence.is_empty())
+            .collect()
     }
 
-    fn parameter_is_optional_by_required_string(&self, required_string: String) -> bool {
-        self.trim_whitespace(required_string).starts_with(Self::OPTIONAL_STR)
+    /// Get all sentences of a description which mention a return value. Sentences starting with
+    /// "On success" or "Returns" come first, as the word "return" is also used in other contexts.
+    fn get_return_sentences(&self, description_string: &str) -> Vec<String> {
+        let (mut preferred, others): (Vec<String>, Vec<String>) = self.split_sentences(description_string)
+            .into_iter()
+            .filter(|sentence| sentence.to_lowercase().contains(Self::RETURN_STR))
+            .partition(|sentence| sentence.starts_with(Self::ON_SUCCESS_STR) || sentence.starts_with(Self::RETURNS_STR));
+
+        preferred.extend(others);
+        preferred
     }
 
-    /// Create the descriptor from the given type string. Currently, if
-    /// the can be either Integer or String, String is preferred.
-    fn create_base_parameter_type_from_string(&self, type_string: String) -> TypeDescriptor {
-        match type_string.as_str() {
-            Self::INTEGER_STR => TypeDescriptor::Integer,
-            Self::STRING_STR => TypeDescriptor::String,
-            Self::INTEGER_OR_STRING_STR => TypeDescriptor::String,
-            Self::BOOLEAN_STR => TypeDescriptor::Boolean,
-            _ => TypeDescriptor::DTO(type_string)
+    /// Find all types mentioned in the given sentence, like Message and True in "the edited Message is
+    /// returned, otherwise True is returned". Types are capitalized words, so the first word of the
+    /// sentence is skipped. A type following "Array of" is wrapped into an array.
+    fn find_types_in_sentence(&self, sentence: &str) -> Result<Vec<TypeDescriptor>, TypeExpressionError> {
+        let words: Vec<&str> = sentence
+            .split_whitespace()
+            .skip(1)
+            .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+            .collect();
+        let mut types = Vec::new();
+        let mut is_array = false;
+
+        for (i, word) in words.iter().enumerate() {
+            let next_word = words.get(i + 1);
+
+            if word.eq_ignore_ascii_case(Self::ARRAY_STR) && next_word == Some(&Self::OF_STR) {
+                is_array = true;
+                continue;
+            }
+
+            if !word.starts_with(char::is_uppercase) {
+                continue;
+            }
+
+            let found_type = match is_array {
+                true => TypeDescriptor::ArrayOf(Box::new(self.create_return_type_from_word(self.to_singular(word, next_word))?)),
+                false => self.create_return_type_from_word(word)?
+            };
+            if !types.contains(&found_type) {
+                types.push(found_type)
+            }
+            is_array = false;
+        }
+
+        Ok(types)
+    }
+
+    /// Array elements are sometimes written in plural, like "an array of the sent Messages".
+    /// If the word is not followed by "objects", a trailing "s" is removed.
+    fn to_singular<'a>(&self, word: &'a str, next_word: Option<&&str>) -> &'a str {
+        match (word.strip_suffix('s'), next_word) {
+            (_, Some(&Self::OBJECTS_STR)) => word,
+            (Some(singular), _) => singular,
+            (None, _) => word
+        }
+    }
+
+    /// The prose uses "Int" instead of "Integer", every other word is a regular type expression.
+    fn create_return_type_from_word(&self, word: &str) -> Result<TypeDescriptor, TypeExpressionError> {
+        match word {
+            Self::INT_STR => Ok(TypeDescriptor::Integer),
+            _ => TypeExpressionParser::parse(word)
         }
     }
 
@@ -61,28 +153,50 @@ impl TypeParserImpl {
 }
 
 impl TypeParser for TypeParserImpl {
-    fn parse_field_type(&self, type_string: String, description_string: String) -> TypeDescriptor {
+    fn parse_field_type(&self, type_string: String, description_string: String) -> Result<TypeDescriptor, TypeExpressionError> {
+        let base_type = self.widen_integer(TypeExpressionParser::parse(&type_string)?, &description_string);
         let optional = self.field_is_optional_by_description(description_string);
-        let base_type = self.create_base_field_type_from_string(type_string);
-        match optional {
-            true => TypeDescriptor::Optional(Box::new(base_type)),
-            false => base_type
+        Ok(self.wrap_optional(base_type, optional))
+    }
+
+    fn parse_parameter_type(&self, type_string: String, description_string: String, requirement: &Requirement) -> Result<TypeDescriptor, TypeExpressionError> {
+        let base_type = self.widen_integer(TypeExpressionParser::parse(&type_string)?, &description_string);
+        Ok(self.wrap_optional(base_type, !requirement.is_required()))
+    }
+
+    fn parse_requirement(&self, required_string: String) -> Requirement {
+        let trimmed = self.trim_whitespace(required_string.clone());
+
+        if trimmed.starts_with(Self::OPTIONAL_STR) {
+            Requirement::Optional
+        } else if trimmed == Self::YES_STR || trimmed == Self::REQUIRED_STR {
+            Requirement::Required
+        } else {
+            Requirement::Unknown(required_string)
         }
     }
 
-    fn parse_parameter_type(&self, type_string: String, required_string: String) -> TypeDescriptor {
-        let optional = self.parameter_is_optional_by_required_string(required_string);
-        let base_type = self.create_base_parameter_type_from_string(type_string);
-        match optional {
-            true => TypeDescriptor::Optional(Box::new(base_type)),
-            false => base_type
+    /// Extract the return type of a method from its description, like "On success, the sent Message is returned".
+    /// If the first sentence with a type mentions more than one, they are combined into a OneOf.
+    fn parse_return_type(&self, description_string: String) -> Result<Option<TypeDescriptor>, TypeExpressionError> {
+        for sentence in self.get_return_sentences(&description_string) {
+            let mut types = self.find_types_in_sentence(&sentence)?;
+
+            match types.len() {
+                0 => continue,
+                1 => return Ok(Some(types.remove(0))),
+                _ => return Ok(Some(TypeDescriptor::OneOf(types)))
+            }
         }
+
+        Ok(None)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::api_parser::type_parser::{TypeParser, TypeParserImpl};
+    use crate::raw_api::requirement::Requirement;
     use crate::raw_api::type_descriptor::TypeDescriptor;
 
     #[test]
@@ -90,17 +204,37 @@ mod tests {
         let type_string = String::from("Integer");
         let description = String::from("description");
 
-        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description);
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::Integer)
     }
 
+    #[test]
+    fn success_integer64_field() {
+        let type_string = String::from("Integer");
+        let description = String::from("Optional. The group has been migrated to a supergroup with the specified identifier. This number may be greater than 32 bits and some programming languages may have difficulty/silent defects in interpreting it.");
+
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::Optional(Box::new(TypeDescriptor::Integer64)))
+    }
+
+    #[test]
+    fn success_integer64_in_one_of_field() {
+        let type_string = String::from("Integer or String");
+        let description = String::from("Unique identifier for the target chat. This number may have more than 32 significant bits.");
+
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::OneOf(vec![TypeDescriptor::Integer64, TypeDescriptor::String]))
+    }
+
     #[test]
     fn success_string_field() {
         let type_string = String::from("String");
         let description = String::from("description");
 
-        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description);
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::String)
     }
@@ -110,17 +244,37 @@ mod tests {
         let type_string = String::from("Boolean");
         let description = String::from("description");
 
-        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description);
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::Boolean)
     }
 
+    #[test]
+    fn success_float_field() {
+        let type_string = String::from("Float");
+        let description = String::from("description");
+
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::Float)
+    }
+
+    #[test]
+    fn success_float_number_field() {
+        let type_string = String::from("Float number");
+        let description = String::from("description");
+
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::Float)
+    }
+
     #[test]
     fn success_dto_field() {
         let type_string = String::from("SomeDTO");
         let description = String::from("description");
 
-        let type_descriptor = TypeParserImpl.parse_field_type(type_string.clone(), description);
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string.clone(), description).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::DTO(type_string))
     }
@@ -130,7 +284,7 @@ mod tests {
         let type_string = String::from("SomeDTO");
         let description = String::from("Optional. description");
 
-        let type_descriptor = TypeParserImpl.parse_field_type(type_string.clone(), description);
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string.clone(), description).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::Optional(Box::new(TypeDescriptor::DTO(type_string))))
     }
@@ -140,7 +294,7 @@ mod tests {
         let type_string = String::from("Array of SomeDTO");
         let description = String::from("description");
 
-        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description);
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("SomeDTO")))))
     }
@@ -150,27 +304,50 @@ mod tests {
         let type_string = String::from("Array of SomeDTO");
         let description = String::from("Optional. description");
 
-        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description);
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::Optional(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("SomeDTO")))))))
     }
 
+    #[test]
+    fn success_one_of_field() {
+        let type_string = String::from("InputFile or String");
+        let description = String::from("description");
+
+        let type_descriptor = TypeParserImpl.parse_field_type(type_string, description).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::OneOf(vec![TypeDescriptor::DTO(String::from("InputFile")), TypeDescriptor::String]))
+    }
+
     #[test]
     fn success_integer_parameter() {
         let type_string = String::from("Integer");
-        let required_string = String::from("Required");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
 
-        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, required_string);
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::Integer)
     }
 
+    #[test]
+    fn success_integer64_parameter() {
+        let type_string = String::from("Integer");
+        let description = String::from("Unique identifier of the target user. This number may have more than 32 significant bits.");
+        let requirement = Requirement::Required;
+
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::Integer64)
+    }
+
     #[test]
     fn success_string_parameter() {
         let type_string = String::from("String");
-        let required_string = String::from("Required");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
 
-        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, required_string);
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::String)
     }
@@ -178,29 +355,97 @@ mod tests {
     #[test]
     fn success_integer_or_string_parameter() {
         let type_string = String::from("Integer or String");
-        let required_string = String::from("Required");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
 
-        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, required_string);
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
 
-        assert_eq!(type_descriptor, TypeDescriptor::String)
+        assert_eq!(type_descriptor, TypeDescriptor::OneOf(vec![TypeDescriptor::Integer, TypeDescriptor::String]))
+    }
+
+    #[test]
+    fn success_multiple_dtos_parameter() {
+        let type_string = String::from("InlineKeyboardMarkup or ReplyKeyboardMarkup or ForceReply");
+        let description = String::from("description");
+        let requirement = Requirement::Optional;
+
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
+
+        let expected = TypeDescriptor::OneOf(vec![
+            TypeDescriptor::DTO(String::from("InlineKeyboardMarkup")),
+            TypeDescriptor::DTO(String::from("ReplyKeyboardMarkup")),
+            TypeDescriptor::DTO(String::from("ForceReply"))
+        ]);
+        assert_eq!(type_descriptor, TypeDescriptor::Optional(Box::new(expected)))
+    }
+
+    #[test]
+    fn success_array_parameter() {
+        let type_string = String::from("Array of String");
+        let description = String::from("description");
+        let requirement = Requirement::Optional;
+
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::Optional(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::String)))))
+    }
+
+    #[test]
+    fn success_nested_array_parameter() {
+        let type_string = String::from("Array of Array of PhotoSize");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
+
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
+
+        let expected = TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("PhotoSize"))))));
+        assert_eq!(type_descriptor, expected)
+    }
+
+    #[test]
+    fn success_array_of_union_parameter() {
+        let type_string = String::from("Array of InputMediaPhoto and InputMediaVideo");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
+
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
+
+        let expected = TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::OneOf(vec![
+            TypeDescriptor::DTO(String::from("InputMediaPhoto")),
+            TypeDescriptor::DTO(String::from("InputMediaVideo"))
+        ])));
+        assert_eq!(type_descriptor, expected)
     }
 
     #[test]
     fn success_boolean_parameter() {
         let type_string = String::from("Boolean");
-        let required_string = String::from("Required");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
 
-        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, required_string);
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::Boolean)
     }
 
+    #[test]
+    fn success_float_number_parameter() {
+        let type_string = String::from("Float number");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
+
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string, description, &requirement).unwrap();
+
+        assert_eq!(type_descriptor, TypeDescriptor::Float)
+    }
+
     #[test]
     fn success_dto_parameter() {
         let type_string = String::from("SomeDTO");
-        let required_string = String::from("Required");
+        let description = String::from("description");
+        let requirement = Requirement::Required;
 
-        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string.clone(), required_string);
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string.clone(), description, &requirement).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::DTO(type_string))
     }
@@ -208,10 +453,112 @@ mod tests {
     #[test]
     fn success_optional_parameter() {
         let type_string = String::from("SomeDTO");
-        let required_string = String::from("Optional");
+        let description = String::from("description");
+        let requirement = Requirement::Optional;
 
-        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string.clone(), required_string);
+        let type_descriptor = TypeParserImpl.parse_parameter_type(type_string.clone(), description, &requirement).unwrap();
 
         assert_eq!(type_descriptor, TypeDescriptor::Optional(Box::new(TypeDescriptor::DTO(type_string))))
     }
+
+    #[test]
+    fn success_dto_return_type() {
+        let description = String::from("Use this method to send text messages. On success, the sent Message is returned.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::DTO(String::from("Message"))))
+    }
+
+    #[test]
+    fn success_true_return_type() {
+        let description = String::from("Use this method to remove webhook integration if you decide to switch back to getUpdates. Returns True on success.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::True))
+    }
+
+    #[test]
+    fn success_int_return_type() {
+        let description = String::from("Use this method to get the number of members in a chat. Returns Int on success.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::Integer))
+    }
+
+    #[test]
+    fn success_array_return_type() {
+        let description = String::from("Use this method to receive incoming updates using long polling. An Array of Update objects is returned.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("Update"))))))
+    }
+
+    #[test]
+    fn success_plural_array_return_type() {
+        let description = String::from("Use this method to send a group of photos or videos as an album. On success, an array of the sent Messages is returned.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("Message"))))))
+    }
+
+    #[test]
+    fn success_return_type_preferred_sentence() {
+        let description = String::from("The user will not be able to re-submit their Passport to you until the errors are fixed (the contents of the field for which you returned the error must change). Returns True on success.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::True))
+    }
+
+    #[test]
+    fn success_return_type_missing_space() {
+        let description = String::from("If the bot has can_delete_messages permission in a supergroup or a channel, it can delete any message there.Returns True on success.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::True))
+    }
+
+    #[test]
+    fn success_no_return_type() {
+        let description = String::from("A nice method.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, None)
+    }
+
+    #[test]
+    fn success_one_of_return_type() {
+        let description = String::from("Use this method to edit text messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise True is returned.");
+
+        let type_descriptor = TypeParserImpl.parse_return_type(description).unwrap();
+
+        assert_eq!(type_descriptor, Some(TypeDescriptor::OneOf(vec![TypeDescriptor::DTO(String::from("Message")), TypeDescriptor::True])))
+    }
+
+    #[test]
+    fn failure_invalid_return_type() {
+        let description = String::from("Use this method to do something. Returns the Bot's answer on success.");
+
+        let result = TypeParserImpl.parse_return_type(description);
+
+        assert!(result.is_err())
+    }
+
+    #[test]
+    fn success_requirement() {
+        let input_expected = vec![
+            ("Yes", Requirement::Required),
+            (" Optional\n", Requirement::Optional),
+            ("Sometimes", Requirement::Unknown(String::from("Sometimes")))
+        ];
+
+        input_expected.into_iter().for_each(|(input, expected)| assert_eq!(TypeParserImpl.parse_requirement(String::from(input)), expected));
+    }
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
 use std::fs::File;
+use std::io::stdout;
+
+use clap::{Arg, Command};
 
 use crate::api_parser::ApiParser;
 use crate::api_parser::scraper::ScraperImpl;
@@ -6,12 +9,48 @@ use crate::code_generator::CodeGenerator;
 use crate::code_generator::configuration_reader::ConfigurationReader;
 use crate::code_generator::renderer::{Renderer, RendererImpl};
 use crate::code_writer::CodeWriter;
+use crate::raw_api::{RawApi, RawApiDocument};
+use crate::raw_api::raw_api_reader::RawApiReader;
+use crate::raw_api::overlay::Overlay;
 use crate::api_parser::type_parser::TypeParserImpl;
+use crate::api_parser::description_parser::DescriptionParserImpl;
 
 pub struct ApiParserApplication;
 
 impl ApiParserApplication {
+    const DUMP_COMMAND: &'static str = "dump";
+    const OUTPUT_ARG: &'static str = "output";
+    const INPUT_ARG: &'static str = "input";
+    const STRICT_ARG: &'static str = "strict";
+
     pub fn run(&self) {
+        let matches = Command::new("telegram_bot_api_parser")
+            .about("Generates code for the telegram bot api from its HTML documentation")
+            .arg(Arg::new(Self::INPUT_ARG)
+                .long(Self::INPUT_ARG)
+                .short('i')
+                .takes_value(true)
+                .help("A JSON file written by dump to generate the code from, instead of the HTML"))
+            .arg(Arg::new(Self::STRICT_ARG)
+                .long(Self::STRICT_ARG)
+                .global(true)
+                .help("Stop at the first part of the api which can't be read or parsed, instead of skipping it with a warning"))
+            .subcommand(Command::new(Self::DUMP_COMMAND)
+                .about("Writes the parsed api as JSON instead of generating code")
+                .arg(Arg::new(Self::OUTPUT_ARG)
+                    .long(Self::OUTPUT_ARG)
+                    .short('o')
+                    .takes_value(true)
+                    .help("The file to write the JSON to. Uses stdout if missing")))
+            .get_matches();
+
+        match matches.subcommand_matches(Self::DUMP_COMMAND) {
+            Some(dump_matches) => self.dump(dump_matches.value_of(Self::OUTPUT_ARG), dump_matches.is_present(Self::STRICT_ARG)),
+            None => self.generate(matches.value_of(Self::INPUT_ARG), matches.is_present(Self::STRICT_ARG))
+        }
+    }
+
+    fn generate(&self, input_path: Option<&str>, strict: bool) {
         let configuration = match ConfigurationReader.read() {
             Ok(configuration) => configuration,
             Err(error) => {
@@ -20,23 +59,21 @@ impl ApiParserApplication {
             }
         };
 
-        let api_html = match File::open("html/api.html") {
-            Ok(file) => file,
-            Err(error) => {
-                eprintln!("Error while opening the api HTML file: {}", error);
-                return;
-            }
+        let raw_api = match input_path {
+            Some(input_path) => self.read_api(input_path),
+            None => self.parse_api(strict)
+        };
+        let mut raw_api = match raw_api {
+            Some(raw_api) => raw_api,
+            None => return
         };
 
-        let scraper = match ScraperImpl::from_html(api_html) {
-            Ok(scraper) => scraper,
-            Err(error) => {
-                eprintln!("An error occurred while scraping the HTML: {}", error);
+        if let Some(overlay_file) = &configuration.overlay_file {
+            if let Err(error) = Overlay::read(overlay_file).and_then(|overlay| overlay.apply(&mut raw_api, &DescriptionParserImpl::new())) {
+                eprintln!("An error occurred while applying the overlay {}: {}", overlay_file, error);
                 return;
             }
-        };
-        let parser = ApiParser::new(scraper, TypeParserImpl);
-        let raw_api = parser.parse();
+        }
 
         let generator = CodeGenerator::new(configuration.clone(), RendererImpl::from_configuration(configuration).unwrap());
         let target_files = match generator.generate(raw_api) {
@@ -51,5 +88,78 @@ impl ApiParserApplication {
             eprintln!("An error ocurred while writing the code: {}", error)
         }
     }
-}
 
+    /// Write the parsed api as versioned JSON, see docs/raw_api_schema.md.
+    fn dump(&self, output_path: Option<&str>, strict: bool) {
+        let raw_api = match self.parse_api(strict) {
+            Some(raw_api) => raw_api,
+            None => return
+        };
+        let document = RawApiDocument::new(raw_api);
+
+        let result = match output_path {
+            Some(output_path) => File::create(output_path).map_err(serde_json::Error::io).and_then(|file| serde_json::to_writer_pretty(file, &document)),
+            None => serde_json::to_writer_pretty(stdout(), &document)
+        };
+
+        if let Err(error) = result {
+            eprintln!("An error occurred while writing the api as JSON: {}", error)
+        }
+    }
+
+    fn read_api(&self, input_path: &str) -> Option<RawApi> {
+        match RawApiReader.read(input_path) {
+            Ok(raw_api) => Some(raw_api),
+            Err(error) => {
+                eprintln!("An error occurred while reading the api from JSON: {}", error);
+                None
+            }
+        }
+    }
+
+    fn parse_api(&self, strict: bool) -> Option<RawApi> {
+        let api_html = match File::open("html/api.html") {
+            Ok(file) => file,
+            Err(error) => {
+                eprintln!("Error while opening the api HTML file: {}", error);
+                return None;
+            }
+        };
+
+        let scraper_result = if strict {
+            ScraperImpl::from_html(api_html)
+        } else {
+            ScraperImpl::from_html_lenient(api_html).map(|(scraper, diagnostics)| {
+                for diagnostic in diagnostics {
+                    eprintln!("Warning: {}", diagnostic);
+                }
+                scraper
+            })
+        };
+        let scraper = match scraper_result {
+            Ok(scraper) => scraper,
+            Err(error) => {
+                eprintln!("An error occurred while scraping the HTML: {}", error);
+                return None;
+            }
+        };
+        let parser = ApiParser::new(scraper, TypeParserImpl, DescriptionParserImpl::new());
+        let parser_result = if strict {
+            parser.parse()
+        } else {
+            parser.parse_lenient().map(|(raw_api, diagnostics)| {
+                for diagnostic in diagnostics {
+                    eprintln!("Warning: {}", diagnostic);
+                }
+                raw_api
+            })
+        };
+        match parser_result {
+            Ok(raw_api) => Some(raw_api),
+            Err(error) => {
+                eprintln!("An error occurred while parsing the api: {}", error);
+                None
+            }
+        }
+    }
+}
\ No newline at end of file
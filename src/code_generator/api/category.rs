@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+use crate::code_generator::api::dto::Dto;
+use crate::code_generator::api::method::Method;
+use crate::code_generator::api::union::Union;
+use crate::code_generator::api::Api;
+use crate::code_generator::names::Names;
+
+pub type Categories<'a> = Vec<Category<'a>>;
+
+/// A section of the api doc (like "Stickers") with the DTOs, methods and unions defined in it.
+#[derive(Serialize)]
+pub struct Category<'a> {
+    name: Names,
+    dtos: Vec<&'a Dto>,
+    methods: Vec<&'a Method>,
+    unions: Vec<&'a Union>
+}
+
+impl<'a> Category<'a> {
+    fn new(name: Names) -> Self {
+        Category {
+            name,
+            dtos: Vec::new(),
+            methods: Vec::new(),
+            unions: Vec::new()
+        }
+    }
+
+    /// Group the DTOs, methods and unions of the api by their category, in the order their sections
+    /// appear in the api doc. Categories the doc doesn't know (like ones added by an overlay) come last.
+    /// Entries without a category are not part of any category.
+    pub fn from_api(api: &'a Api) -> Categories<'a> {
+        let mut categories = Vec::new();
+
+        for dto in &api.dtos {
+            if let Some(name) = dto.category() {
+                Self::get_or_insert(&mut categories, name).dtos.push(dto)
+            }
+        }
+
+        for method in &api.methods {
+            if let Some(name) = method.category() {
+                Self::get_or_insert(&mut categories, name).methods.push(method)
+            }
+        }
+
+        for union in &api.unions {
+            if let Some(name) = union.category() {
+                Self::get_or_insert(&mut categories, name).unions.push(union)
+            }
+        }
+
+        categories.sort_by_key(|category| api.category_order.iter().position(|name| name == &category.name).unwrap_or(usize::MAX));
+        categories
+    }
+
+    fn get_or_insert<'b>(categories: &'b mut Categories<'a>, name: &Names) -> &'b mut Category<'a> {
+        match categories.iter().position(|category| &category.name == name) {
+            Some(index) => &mut categories[index],
+            None => {
+                categories.push(Category::new(name.clone()));
+                categories.last_mut().unwrap()
+            }
+        }
+    }
+}
\ No newline at end of file
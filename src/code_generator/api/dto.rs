@@ -1,8 +1,9 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
 use serde::Serialize;
 
 use crate::code_generator::api::field::Field;
+use crate::code_generator::api::reference_graph::ReferenceGraph;
 use crate::code_generator::names::Names;
 use crate::code_generator::renderer::Renderer;
 use crate::raw_api::raw_dto::RawDto;
@@ -10,29 +11,44 @@ use crate::raw_api::raw_dto::RawDto;
 #[derive(Serialize)]
 pub struct Dto {
     name: Names,
+    description: String,
+    category: Option<Names>,
     fields: Vec<Field>,
-    used_dto_names: HashSet<Names>,
+    used_dto_names: BTreeSet<Names>,
+    discriminator_value: Option<String>,
 }
 
 impl Dto {
-    pub fn new<R: Renderer>(raw_dto: RawDto, renderer: &R) -> Result<Self, R::Error> {
+    pub fn new<R: Renderer>(raw_dto: RawDto, reference_graph: &ReferenceGraph, renderer: &R) -> Result<Self, R::Error> {
         let name = Names::new(&raw_dto.name);
         let mut fields = Vec::new();
-        let mut used_dto_names = HashSet::new();
+        let mut used_dto_names = BTreeSet::new();
+        let mut discriminator_value = None;
 
         for raw_field in raw_dto.fields {
-            if let Some(dto_name) = raw_field.field_type.get_dto_name() {
-                if name.camel_case != dto_name {
+            if raw_field.discriminator_value.is_some() {
+                discriminator_value = raw_field.discriminator_value.clone();
+            }
+            for dto_name in raw_field.field_type.get_dto_names() {
+                if raw_dto.name != dto_name {
                     used_dto_names.insert(Names::new(&dto_name));
                 }
             }
-            fields.push(Field::new(raw_field, &name, renderer)?);
+            let is_recursive = reference_graph.is_recursive(&raw_dto.name, &raw_field.name);
+            fields.push(Field::new(raw_field, &name, is_recursive, renderer)?);
         }
 
         Ok(Dto {
             name,
+            description: raw_dto.description,
+            category: raw_dto.category.as_deref().map(Names::from_title),
             fields,
             used_dto_names,
+            discriminator_value,
         })
     }
+
+    pub fn category(&self) -> Option<&Names> {
+        self.category.as_ref()
+    }
 }
\ No newline at end of file
@@ -2,22 +2,46 @@ use serde::Serialize;
 
 use crate::code_generator::names::Names;
 use crate::code_generator::renderer::Renderer;
+use crate::raw_api::constraints::Constraints;
 use crate::raw_api::raw_field::RawField;
+use crate::raw_api::type_descriptor::TypeDescriptor;
 
 #[derive(Serialize)]
 pub struct Field {
     name: String,
     field_type: String,
+    /// The type without the optional around it, like Message for Option<Message>. Templates need it
+    /// to store a recursive field behind a pointer inside of the optional.
+    inner_type: String,
+    is_required: bool,
+    /// True if the field closes a cycle of DTOs, like Message.reply_to_message.
+    is_recursive: bool,
+    description: String,
+    allowed_values: Vec<String>,
+    discriminator_value: Option<String>,
+    constraints: Constraints,
 }
 
 impl Field {
-    pub fn new<R: Renderer>(raw_field: RawField, dto_name: &Names, renderer: &R) -> Result<Self, R::Error> {
+    pub fn new<R: Renderer>(raw_field: RawField, dto_name: &Names, is_recursive: bool, renderer: &R) -> Result<Self, R::Error> {
         let name = renderer.render_rename(raw_field.name.clone(), dto_name).unwrap();
         let field_type = renderer.render_type(&raw_field.field_type).unwrap();
+        let inner_type = match &raw_field.field_type {
+            TypeDescriptor::Optional(optional_type) => renderer.render_type(optional_type).unwrap(),
+            _ => field_type.clone()
+        };
+        let is_required = !raw_field.field_type.is_optional();
 
         Ok(Field {
             name,
             field_type,
+            inner_type,
+            is_required,
+            is_recursive,
+            description: raw_field.description,
+            allowed_values: raw_field.allowed_values,
+            discriminator_value: raw_field.discriminator_value,
+            constraints: raw_field.constraints,
         })
     }
 }
\ No newline at end of file
@@ -3,34 +3,56 @@ use serde::Serialize;
 use crate::code_generator::api::parameter::Parameter;
 use crate::code_generator::renderer::Renderer;
 use crate::raw_api::raw_method::RawMethod;
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 use crate::code_generator::names::Names;
 
 #[derive(Serialize)]
 pub struct Method {
     name: Names,
+    description: String,
+    category: Option<Names>,
     parameters: Vec<Parameter>,
-    used_dto_names: HashSet<Names>
+    return_type: Option<String>,
+    used_dto_names: BTreeSet<Names>,
+    requires_multipart: bool
 }
 
 impl Method {
     pub fn new<R: Renderer>(raw_method: RawMethod, renderer: &R) -> Result<Self, R::Error> {
         let name = Names::new(&raw_method.name);
         let mut parameters = Vec::new();
-        let mut used_dto_names = HashSet::new();
+        let mut used_dto_names = BTreeSet::new();
 
         for raw_parameter in raw_method.parameters {
-            if let Some(dto_name) = raw_parameter.parameter_type.get_dto_name() {
+            for dto_name in raw_parameter.parameter_type.get_dto_names() {
                 used_dto_names.insert(Names::new(&dto_name));
             }
 
             parameters.push(Parameter::new(raw_parameter, renderer)?)
         }
 
+        let return_type = match raw_method.return_type {
+            Some(return_type) => {
+                for dto_name in return_type.get_dto_names() {
+                    used_dto_names.insert(Names::new(&dto_name));
+                }
+                Some(renderer.render_type(&return_type)?)
+            }
+            None => None
+        };
+
         Ok(Method {
             name,
+            description: raw_method.description,
+            category: raw_method.category.as_deref().map(Names::from_title),
             parameters,
-            used_dto_names
+            return_type,
+            used_dto_names,
+            requires_multipart: raw_method.requires_multipart
         })
     }
-}
\ No newline at end of file
+
+    pub fn category(&self) -> Option<&Names> {
+        self.category.as_ref()
+    }
+}
@@ -1,39 +1,58 @@
 use serde::Serialize;
 use crate::code_generator::api::dto::Dto;
 use crate::code_generator::api::method::Method;
+use crate::code_generator::api::reference_graph::ReferenceGraph;
+use crate::code_generator::api::union::Union;
+use crate::code_generator::names::Names;
 use crate::code_generator::renderer::Renderer;
 use crate::raw_api::RawApi;
 
+pub mod category;
 pub mod dto;
 pub mod method;
+pub mod union;
 mod field;
 mod parameter;
+mod reference_graph;
 
 pub type Dtos = Vec<Dto>;
 pub type Methods = Vec<Method>;
+pub type Unions = Vec<Union>;
 
 #[derive(Serialize)]
 pub struct Api {
     pub dtos: Dtos,
-    pub methods: Methods
+    pub methods: Methods,
+    pub unions: Unions,
+    /// The names of the categories in the order their sections appear in the api doc.
+    pub category_order: Vec<Names>
 }
 
 impl Api {
     pub  fn new<R: Renderer>(raw_api: RawApi, renderer: &R) -> Result<Self, R::Error> {
         let mut dtos = Vec::new();
         let mut methods = Vec::new();
+        let mut unions = Vec::new();
+        let category_order = raw_api.categories.iter().map(|category| Names::from_title(category)).collect();
+        let reference_graph = ReferenceGraph::new(&raw_api.raw_dtos, &raw_api.raw_unions);
 
         for raw_dto in raw_api.raw_dtos {
-            dtos.push(Dto::new(raw_dto, renderer)?)
+            dtos.push(Dto::new(raw_dto, &reference_graph, renderer)?)
         }
 
         for raw_method in raw_api.raw_methods {
             methods.push(Method::new(raw_method, renderer)?)
         }
 
+        for raw_union in raw_api.raw_unions {
+            unions.push(Union::new(raw_union))
+        }
+
         Ok(Api {
             dtos,
-            methods
+            methods,
+            unions,
+            category_order
         })
     }
 }
\ No newline at end of file
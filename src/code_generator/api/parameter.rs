@@ -2,12 +2,19 @@ use serde::Serialize;
 
 use crate::code_generator::names::Names;
 use crate::code_generator::renderer::Renderer;
+use crate::raw_api::constraints::Constraints;
 use crate::raw_api::raw_parameter::RawParameter;
 
 #[derive(Serialize)]
 pub struct Parameter {
     name: String,
-    parameter_type: String
+    parameter_type: String,
+    is_required: bool,
+    description: String,
+    allowed_values: Vec<String>,
+    constraints: Constraints,
+    is_file: bool,
+    json_serialized: bool
 }
 
 impl Parameter {
@@ -20,10 +27,17 @@ impl Parameter {
             None => raw_parameter.name
         };
         let parameter_type = renderer.render_type(&raw_parameter.parameter_type).unwrap();
+        let is_required = raw_parameter.requirement.is_required();
 
         Ok(Parameter {
             name,
-            parameter_type
+            parameter_type,
+            is_required,
+            description: raw_parameter.description,
+            allowed_values: raw_parameter.allowed_values,
+            constraints: raw_parameter.constraints,
+            is_file: raw_parameter.is_file,
+            json_serialized: raw_parameter.json_serialized
         })
     }
 }
\ No newline at end of file
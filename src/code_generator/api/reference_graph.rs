@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::raw_api::{RawDtos, RawUnions};
+use crate::raw_api::type_descriptor::TypeDescriptor;
+
+/// Finds the fields which have to be stored behind a pointer because they close a cycle of DTOs,
+/// like Message.reply_to_message. Only one field per cycle is chosen: the references of all fields
+/// are added one by one, and a field whose references would lead back to its own DTO is marked
+/// as recursive instead of being added. Required fields are added first, so optional ones
+/// (like Chat.pinned_message) are chosen over required ones (like Message.chat).
+/// References inside arrays are left out, since a Vec already adds the indirection a cycle needs.
+pub struct ReferenceGraph {
+    recursive_fields: HashSet<(String, String)>,
+}
+
+impl ReferenceGraph {
+    pub fn new(raw_dtos: &RawDtos, raw_unions: &RawUnions) -> Self {
+        let mut references: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut recursive_fields = HashSet::new();
+
+        for raw_union in raw_unions {
+            references.insert(raw_union.name.clone(), raw_union.members.iter().cloned().collect());
+        }
+
+        let (required_fields, optional_fields): (Vec<_>, Vec<_>) = raw_dtos.iter()
+            .flat_map(|raw_dto| raw_dto.fields.iter().map(move |raw_field| (&raw_dto.name, raw_field)))
+            .partition(|(_, raw_field)| !raw_field.field_type.is_optional());
+
+        for (dto_name, raw_field) in required_fields.into_iter().chain(optional_fields) {
+            let field_references = get_inline_dto_names(&raw_field.field_type);
+            if Self::leads_to(&references, field_references.clone(), dto_name) {
+                recursive_fields.insert((dto_name.clone(), raw_field.name.clone()));
+            } else {
+                references.entry(dto_name.clone()).or_default().extend(field_references);
+            }
+        }
+
+        ReferenceGraph {
+            recursive_fields
+        }
+    }
+
+    /// Check if the given field was chosen to break a cycle of DTOs.
+    pub fn is_recursive(&self, dto_name: &str, field_name: &str) -> bool {
+        self.recursive_fields.contains(&(String::from(dto_name), String::from(field_name)))
+    }
+
+    /// Check if one of the names leads to the target, directly or through the references added so far.
+    fn leads_to(references: &HashMap<String, HashSet<String>>, mut pending: Vec<String>, target: &str) -> bool {
+        let mut visited = HashSet::new();
+
+        while let Some(name) = pending.pop() {
+            if name == target {
+                return true;
+            }
+            if !visited.insert(name.clone()) {
+                continue;
+            }
+            if let Some(name_references) = references.get(&name) {
+                pending.extend(name_references.iter().cloned())
+            }
+        }
+
+        false
+    }
+}
+
+/// Get the names of the DTOs a value of the given type contains without an array in between.
+fn get_inline_dto_names(type_descriptor: &TypeDescriptor) -> Vec<String> {
+    match type_descriptor {
+        TypeDescriptor::DTO(dto_name) => vec![dto_name.clone()],
+        TypeDescriptor::Optional(optional_type) => get_inline_dto_names(optional_type),
+        TypeDescriptor::OneOf(one_of_types) => one_of_types.iter().flat_map(get_inline_dto_names).collect(),
+        _ => Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::code_generator::api::reference_graph::ReferenceGraph;
+    use crate::raw_api::constraints::Constraints;
+    use crate::raw_api::raw_dto::RawDto;
+    use crate::raw_api::raw_field::RawField;
+    use crate::raw_api::raw_union::RawUnion;
+    use crate::raw_api::type_descriptor::TypeDescriptor;
+
+    fn dto(name: &str) -> TypeDescriptor {
+        TypeDescriptor::DTO(String::from(name))
+    }
+
+    fn optional(type_descriptor: TypeDescriptor) -> TypeDescriptor {
+        TypeDescriptor::Optional(Box::new(type_descriptor))
+    }
+
+    fn create_raw_dto(name: &str, fields: Vec<(&str, TypeDescriptor)>) -> RawDto {
+        let fields = fields.into_iter()
+            .map(|(field_name, field_type)| RawField::new(String::from(field_name), field_type, String::new(), Vec::new(), None, Constraints::default()))
+            .collect();
+        RawDto::new(String::from(name), String::new(), fields)
+    }
+
+    fn create_graph() -> ReferenceGraph {
+        let raw_dtos = vec![
+            create_raw_dto("Chat", vec![("pinned_message", optional(dto("Message")))]),
+            create_raw_dto("Message", vec![("reply_to_message", optional(dto("Message"))), ("chat", dto("Chat")), ("from", dto("User")), ("forward_from_chat", optional(dto("Chat")))]),
+            create_raw_dto("User", Vec::new()),
+            create_raw_dto("InputMediaVideo", vec![("media", optional(dto("InputMedia")))]),
+            create_raw_dto("Poll", vec![("options", TypeDescriptor::ArrayOf(Box::new(dto("PollOption"))))]),
+            create_raw_dto("PollOption", vec![("poll", optional(dto("Poll")))]),
+        ];
+        let raw_unions = vec![RawUnion { name: String::from("InputMedia"), description: String::new(), category: None, members: vec![String::from("InputMediaVideo")] }];
+
+        ReferenceGraph::new(&raw_dtos, &raw_unions)
+    }
+
+    #[test]
+    fn success_self_reference() {
+        assert!(create_graph().is_recursive("Message", "reply_to_message"))
+    }
+
+    #[test]
+    fn success_one_field_per_cycle() {
+        let graph = create_graph();
+
+        assert!(graph.is_recursive("Chat", "pinned_message"));
+        assert!(!graph.is_recursive("Message", "chat"));
+        assert!(!graph.is_recursive("Message", "forward_from_chat"))
+    }
+
+    #[test]
+    fn success_cyclic_reference_through_union() {
+        assert!(create_graph().is_recursive("InputMediaVideo", "media"))
+    }
+
+    #[test]
+    fn success_no_recursion_through_array() {
+        let graph = create_graph();
+
+        assert!(!graph.is_recursive("Poll", "options"));
+        assert!(!graph.is_recursive("PollOption", "poll"))
+    }
+
+    #[test]
+    fn success_no_recursion() {
+        assert!(!create_graph().is_recursive("Message", "from"))
+    }
+}
\ No newline at end of file
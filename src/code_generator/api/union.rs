@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::code_generator::names::Names;
+use crate::raw_api::raw_union::RawUnion;
+
+#[derive(Serialize)]
+pub struct Union {
+    name: Names,
+    description: String,
+    category: Option<Names>,
+    members: Vec<Names>
+}
+
+impl Union {
+    pub fn new(raw_union: RawUnion) -> Self {
+        let name = Names::new(&raw_union.name);
+        let members = raw_union.members.iter().map(Names::new).collect();
+
+        Union {
+            name,
+            description: raw_union.description,
+            category: raw_union.category.as_deref().map(Names::from_title),
+            members
+        }
+    }
+
+    pub fn category(&self) -> Option<&Names> {
+        self.category.as_ref()
+    }
+}
\ No newline at end of file
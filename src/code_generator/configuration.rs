@@ -4,11 +4,30 @@ use serde::Deserialize;
 #[derive(Deserialize, Debug, Clone)]
 pub struct Configuration {
     pub integer_type: String,
+    /// Used for integers which may need more than 32 bits. Falls back to integer_type if missing.
+    pub integer64_type: Option<String>,
     pub string_type: String,
     pub boolean_type: String,
+    /// Used for the type True, a boolean which is always true. Falls back to boolean_type if missing.
+    pub true_type: Option<String>,
+    /// Used for Floats. Falls back to f64 if missing (like in configurations written before floats were supported).
+    pub float_type: Option<String>,
+    /// Used for Integers holding a unix time. Falls back to the primitive type if missing.
+    pub timestamp_type: Option<String>,
+    /// Used for Strings holding a URL. Falls back to the primitive type if missing.
+    pub url_type: Option<String>,
+    /// Used for Strings holding the id of a file. Falls back to the primitive type if missing.
+    pub file_id_type: Option<String>,
     pub array_type: String,
     pub optional_type: String,
+    /// Used for types with multiple possible values, like "Integer or String". Falls back to the first of these types if missing.
+    pub one_of_type: Option<String>,
     pub renames: Vec<Rename>,
+    /// Types which are used by the api but defined outside of the generated code, like InputFile.
+    #[serde(default)]
+    pub external_types: Vec<String>,
+    /// A JSON file with patches that are applied to the parsed api before generating the code.
+    pub overlay_file: Option<String>,
     pub template_files: Vec<TemplateFile>
 }
 
@@ -25,4 +44,28 @@ pub struct TemplateFile {
     pub template_path: String,
     pub target_path: String,
     pub resolve_strategy: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::code_generator::configuration::Configuration;
+
+    /// A configuration with only the values which were required before floats and one_of types were supported.
+    const MINIMAL_CONFIGURATION: &str = r#"{
+        "integer_type": "u64",
+        "string_type": "String",
+        "boolean_type": "bool",
+        "array_type": "Vec<{{{value}}}>",
+        "optional_type": "Option<{{{value}}}>",
+        "renames": [],
+        "template_files": []
+    }"#;
+
+    #[test]
+    fn success_read_minimal_configuration() {
+        let configuration: Configuration = serde_json::from_str(MINIMAL_CONFIGURATION).unwrap();
+
+        assert_eq!(configuration.float_type, None);
+        assert_eq!(configuration.one_of_type, None)
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::fs::File;
 
-use serde::export::Formatter;
+use std::fmt::Formatter;
 
 use crate::code_generator::configuration::Configuration;
 
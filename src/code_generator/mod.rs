@@ -1,14 +1,14 @@
 use std::convert::TryFrom;
 use std::fmt;
 
-use serde::Serialize;
-
 use crate::code_generator::api::Api;
+use crate::code_generator::api::category::Category;
 use crate::code_generator::configuration::Configuration;
 use crate::code_generator::renderer::{Renderer, RendererError};
 use crate::code_generator::resolve_strategy::{NoValidResolveStrategyError, ResolveStrategy};
 use crate::code_generator::target_files::{SameFilenameError, TargetFiles};
 use crate::raw_api::RawApi;
+use crate::raw_api::validator::{RawApiValidator, UnresolvedTypesError};
 
 pub mod configuration;
 pub mod configuration_reader;
@@ -31,9 +31,12 @@ impl<R: Renderer> CodeGenerator<R> {
         }
     }
 
-    pub fn generate(&self, api: RawApi) -> Result<TargetFiles, TemplateCodeGenerationError> {
+    pub fn generate(&self, api: RawApi) -> Result<TargetFiles, TemplateCodeGenerationError<'_>> {
+        RawApiValidator.validate(&api, &self.configuration.external_types)?;
+
         let mut target_files = TargetFiles::new();
         let api = Api::new(api, &self.renderer)?;
+        let categories = Category::from_api(&api);
 
         for template_file in self.configuration.template_files.iter() {
             let resolve_strategy = ResolveStrategy::try_from(&template_file.resolve_strategy)?;
@@ -51,6 +54,18 @@ impl<R: Renderer> CodeGenerator<R> {
                         target_files.insert(self.renderer.render_for_single_method(method, template_file)?)?
                     }
                 }
+                ResolveStrategy::ForAllUnions => target_files.insert(self.renderer.render_for_all_unions(&api.unions, template_file)?)?,
+                ResolveStrategy::ForEachUnion => {
+                    for union in &api.unions {
+                        target_files.insert(self.renderer.render_for_single_union(union, template_file)?)?
+                    }
+                }
+                ResolveStrategy::ForAllCategories => target_files.insert(self.renderer.render_for_all_categories(&categories, template_file)?)?,
+                ResolveStrategy::ForEachCategory => {
+                    for category in &categories {
+                        target_files.insert(self.renderer.render_for_single_category(category, template_file)?)?
+                    }
+                }
             }
         }
 
@@ -58,16 +73,11 @@ impl<R: Renderer> CodeGenerator<R> {
     }
 }
 
-/// Wraps a single String so it can be processed by handlebars.
-#[derive(Serialize)]
-struct SingleValueHolder {
-    pub value: String
-}
-
 #[derive(Debug)]
 pub enum TemplateCodeGenerationError<'a> {
     NoValidResolveStrategyError(NoValidResolveStrategyError),
     SameFilenameError(SameFilenameError),
+    UnresolvedTypesError(UnresolvedTypesError),
     RendererError(Box<dyn RendererError + 'a>)
 }
 
@@ -78,6 +88,7 @@ impl<'a> std::fmt::Display for TemplateCodeGenerationError<'a> {
         match self {
             TemplateCodeGenerationError::NoValidResolveStrategyError(e) => e.fmt(f),
             TemplateCodeGenerationError::SameFilenameError(e) => e.fmt(f),
+            TemplateCodeGenerationError::UnresolvedTypesError(e) => e.fmt(f),
             TemplateCodeGenerationError::RendererError(e) => e.fmt(f)
         }
     }
@@ -99,4 +110,10 @@ impl<'a> From<SameFilenameError> for TemplateCodeGenerationError<'a> {
     fn from(e: SameFilenameError) -> Self {
         TemplateCodeGenerationError::SameFilenameError(e)
     }
+}
+
+impl<'a> From<UnresolvedTypesError> for TemplateCodeGenerationError<'a> {
+    fn from(e: UnresolvedTypesError) -> Self {
+        TemplateCodeGenerationError::UnresolvedTypesError(e)
+    }
 }
\ No newline at end of file
@@ -2,7 +2,7 @@ use serde::Serialize;
 
 use crate::util::{to_snake_case, to_camel_case, to_capital_camel_case};
 
-#[derive(Serialize, Eq, PartialEq, Hash)]
+#[derive(Serialize, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct Names {
     pub snake_case: String,
     pub camel_case: String,
@@ -11,9 +11,9 @@ pub struct Names {
 
 impl Names {
     pub fn new(dto_name: &String) -> Self {
-        let snake_case = to_snake_case(&dto_name);
-        let camel_case = to_camel_case(&dto_name);
-        let capital_camel_case = to_capital_camel_case(&dto_name);
+        let snake_case = to_snake_case(dto_name);
+        let camel_case = to_camel_case(dto_name);
+        let capital_camel_case = to_capital_camel_case(dto_name);
 
         Names {
             snake_case,
@@ -21,4 +21,28 @@ impl Names {
             capital_camel_case
         }
     }
+
+    /// Create the names for a title with several words (like "Updating messages"),
+    /// which are joined to "UpdatingMessages" first.
+    pub fn from_title(title: &str) -> Self {
+        let joined_words = title.split_whitespace()
+            .map(|word| to_capital_camel_case(&String::from(word)))
+            .collect::<String>();
+
+        Self::new(&joined_words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::code_generator::names::Names;
+
+    #[test]
+    fn success_names_from_title() {
+        let names = Names::from_title(&String::from("Updating messages"));
+
+        assert_eq!(names.snake_case, String::from("updating_messages"));
+        assert_eq!(names.camel_case, String::from("updatingMessages"));
+        assert_eq!(names.capital_camel_case, String::from("UpdatingMessages"))
+    }
 }
\ No newline at end of file
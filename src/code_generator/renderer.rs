@@ -3,12 +3,14 @@ use serde::Serialize;
 
 use crate::code_generator::configuration::{Configuration, Rename, TemplateFile};
 use crate::code_generator::target_files::TargetFile;
-use crate::raw_api::type_descriptor::TypeDescriptor;
+use crate::raw_api::type_descriptor::{SemanticType, TypeDescriptor};
 use crate::code_generator::names::Names;
-use crate::code_generator::api::{Dtos, Methods};
+use crate::code_generator::api::{Dtos, Methods, Unions};
 use crate::code_generator::api::dto::Dto;
 use crate::code_generator::api::method::Method;
-use serde::export::Formatter;
+use crate::code_generator::api::union::Union;
+use crate::code_generator::api::category::{Categories, Category};
+use std::fmt::Formatter;
 
 pub trait Renderer {
     type Error: RendererError;
@@ -24,6 +26,14 @@ pub trait Renderer {
 
     fn render_for_all_methods(&self, method: &Methods, template_file: &TemplateFile) -> Result<TargetFile, Self::Error>;
 
+    fn render_for_single_union(&self, union: &Union, template_file: &TemplateFile) -> Result<TargetFile, Self::Error>;
+
+    fn render_for_all_unions(&self, unions: &Unions, template_file: &TemplateFile) -> Result<TargetFile, Self::Error>;
+
+    fn render_for_single_category(&self, category: &Category, template_file: &TemplateFile) -> Result<TargetFile, Self::Error>;
+
+    fn render_for_all_categories(&self, categories: &Categories, template_file: &TemplateFile) -> Result<TargetFile, Self::Error>;
+
     fn render_type(&self, field_type: &TypeDescriptor) -> Result<String, Self::Error>;
 
     fn render_rename(&self, field_name: String, field_rename_values: &Names) -> Result<String, Self::Error>;
@@ -32,8 +42,14 @@ pub trait Renderer {
 pub struct RendererImpl<'a> {
     registry: Handlebars<'a>,
     integer_type: String,
+    integer64_type: String,
     string_type: String,
-    boolean_type: String
+    boolean_type: String,
+    true_type: String,
+    float_type: String,
+    timestamp_type: Option<String>,
+    url_type: Option<String>,
+    file_id_type: Option<String>
 }
 
 impl<'a> Renderer for RendererImpl<'a> {
@@ -44,6 +60,9 @@ impl<'a> Renderer for RendererImpl<'a> {
 
         Self::register_array_template(&mut registry, configuration.array_type)?;
         Self::register_optional_template(&mut registry, configuration.optional_type)?;
+        if let Some(one_of_type) = configuration.one_of_type {
+            Self::register_one_of_template(&mut registry, one_of_type)?;
+        }
 
         for template_file in &configuration.template_files {
             Self::register_template_file(&mut registry, template_file)?
@@ -53,11 +72,22 @@ impl<'a> Renderer for RendererImpl<'a> {
             Self::register_rename(&mut registry, rename)?
         }
 
+        let integer_type = configuration.integer_type;
+        let integer64_type = configuration.integer64_type.unwrap_or_else(|| integer_type.clone());
+        let boolean_type = configuration.boolean_type;
+        let true_type = configuration.true_type.unwrap_or_else(|| boolean_type.clone());
+
         Ok(RendererImpl {
             registry,
-            integer_type: configuration.integer_type,
+            integer64_type,
+            integer_type,
             string_type: configuration.string_type,
-            boolean_type: configuration.boolean_type
+            boolean_type,
+            true_type,
+            float_type: configuration.float_type.unwrap_or_else(|| String::from(Self::DEFAULT_FLOAT_TYPE)),
+            timestamp_type: configuration.timestamp_type,
+            url_type: configuration.url_type,
+            file_id_type: configuration.file_id_type
         })
     }
 
@@ -77,14 +107,41 @@ impl<'a> Renderer for RendererImpl<'a> {
         self.render_instance(methods, template_file)
     }
 
+    fn render_for_single_union(&self, union: &Union, template_file: &TemplateFile) -> Result<TargetFile, Self::Error> {
+        self.render_instance(union, template_file)
+    }
+
+    fn render_for_all_unions(&self, unions: &Unions, template_file: &TemplateFile) -> Result<TargetFile, Self::Error> {
+        self.render_instance(unions, template_file)
+    }
+
+    fn render_for_single_category(&self, category: &Category, template_file: &TemplateFile) -> Result<TargetFile, Self::Error> {
+        self.render_instance(category, template_file)
+    }
+
+    fn render_for_all_categories(&self, categories: &Categories, template_file: &TemplateFile) -> Result<TargetFile, Self::Error> {
+        self.render_instance(categories, template_file)
+    }
+
     fn render_type(&self, field_type: &TypeDescriptor) -> Result<String, Self::Error> {
         Ok(match field_type {
             TypeDescriptor::Integer => self.integer_type.clone(),
+            TypeDescriptor::Integer64 => self.integer64_type.clone(),
             TypeDescriptor::String => self.string_type.clone(),
             TypeDescriptor::Boolean => self.boolean_type.clone(),
+            TypeDescriptor::True => self.true_type.clone(),
+            TypeDescriptor::Float => self.float_type.clone(),
             TypeDescriptor::DTO(dto_name) => dto_name.clone(),
             TypeDescriptor::ArrayOf(array_field_type) => self.render_array_string(self.render_type(array_field_type)?)?,
-            TypeDescriptor::Optional(optional_field_type) => self.render_optional_string(self.render_type(optional_field_type)?)?
+            TypeDescriptor::Optional(optional_field_type) => self.render_optional_string(self.render_type(optional_field_type)?)?,
+            TypeDescriptor::OneOf(one_of_types) => {
+                let values = one_of_types.iter().map(|one_of_type| self.render_type(one_of_type)).collect::<Result<Vec<String>, Self::Error>>()?;
+                self.render_one_of_string(values)?
+            }
+            TypeDescriptor::Semantic(semantic_type, primitive_type) => match self.get_semantic_type_mapping(semantic_type) {
+                Some(mapped_type) => mapped_type.clone(),
+                None => self.render_type(primitive_type)?
+            }
         })
     }
 
@@ -103,9 +160,20 @@ impl<'a> Renderer for RendererImpl<'a> {
 impl<'a> RendererImpl<'a> {
     const ARRAY_TEMPLATE: &'static str = "array";
     const OPTIONAL_TEMPLATE: &'static str = "optional";
+    const ONE_OF_TEMPLATE: &'static str = "one_of";
+    /// The rust type for Floats, used by configurations written before floats were supported.
+    const DEFAULT_FLOAT_TYPE: &'static str = "f64";
     const FILE_NAME_TEMPLATE_NAME_POSTFIX: &'static str = "_name";
     const RENAME_POSTFIX: &'static str = "_rename";
 
+    fn get_semantic_type_mapping(&self, semantic_type: &SemanticType) -> Option<&String> {
+        match semantic_type {
+            SemanticType::UnixTime => self.timestamp_type.as_ref(),
+            SemanticType::Url => self.url_type.as_ref(),
+            SemanticType::FileId => self.file_id_type.as_ref()
+        }
+    }
+
     fn register_array_template(registry: &mut Handlebars, array_string: String) -> Result<(), HandlebarsRendererError> {
         registry.register_template_string(Self::ARRAY_TEMPLATE, array_string)?;
         Ok(())
@@ -116,9 +184,14 @@ impl<'a> RendererImpl<'a> {
         Ok(())
     }
 
+    fn register_one_of_template(registry: &mut Handlebars, one_of_string: String) -> Result<(), HandlebarsRendererError> {
+        registry.register_template_string(Self::ONE_OF_TEMPLATE, one_of_string)?;
+        Ok(())
+    }
+
     fn register_template_file(registry: &mut Handlebars, template_file: &TemplateFile) -> Result<(), HandlebarsRendererError> {
         let template_path = &template_file.template_path;
-        registry.register_template_string(Self::get_file_name_template_name(&template_path).as_str(), &template_file.target_path)?;
+        registry.register_template_string(Self::get_file_name_template_name(template_path).as_str(), &template_file.target_path)?;
         registry.register_template_file(template_path.as_str(), template_path)?;
         Ok(())
     }
@@ -129,12 +202,14 @@ impl<'a> RendererImpl<'a> {
         Ok(())
     }
 
+    #[allow(clippy::ptr_arg)]
     fn get_file_name_template_name(path: &String) -> String {
         let mut result = String::from(path.as_str());
         result.push_str(Self::FILE_NAME_TEMPLATE_NAME_POSTFIX);
         result
     }
 
+    #[allow(clippy::ptr_arg)]
     fn get_rename_template_name(from: &String) -> String {
         let mut result = String::from(from.as_str());
         result.push_str(Self::RENAME_POSTFIX);
@@ -143,8 +218,8 @@ impl<'a> RendererImpl<'a> {
 
     fn render_instance<T: Serialize>(&self, instance: &T, template_file: &TemplateFile) -> Result<TargetFile, HandlebarsRendererError> {
         let template_path = &template_file.template_path;
-        let file_name = self.registry.render(Self::get_file_name_template_name(&template_path).as_str(), instance)?;
-        let content = self.registry.render(&template_path, instance)?;
+        let file_name = self.registry.render(Self::get_file_name_template_name(template_path).as_str(), instance)?;
+        let content = self.registry.render(template_path, instance)?;
 
         Ok(TargetFile {
             file_name,
@@ -161,15 +236,24 @@ impl<'a> RendererImpl<'a> {
         let optional_string = self.registry.render(Self::OPTIONAL_TEMPLATE, &SingleValueHolder { value })?;
         Ok(optional_string)
     }
+
+    /// Without a one_of_type the first of the possible types is used.
+    fn render_one_of_string(&self, values: Vec<String>) -> Result<String, HandlebarsRendererError> {
+        if !self.registry.has_template(Self::ONE_OF_TEMPLATE) {
+            return Ok(values.into_iter().next().unwrap_or_default());
+        }
+        let one_of_string = self.registry.render(Self::ONE_OF_TEMPLATE, &MultiValueHolder { count: values.len(), values })?;
+        Ok(one_of_string)
+    }
 }
 
 pub trait RendererError: std::error::Error {}
 
 #[derive(Debug)]
 pub enum HandlebarsRendererError {
-    TemplateError(TemplateError),
-    TemplateFileError(TemplateFileError),
-    RenderError(RenderError)
+    TemplateError(Box<TemplateError>),
+    TemplateFileError(Box<TemplateFileError>),
+    RenderError(Box<RenderError>)
 }
 
 impl RendererError for HandlebarsRendererError {}
@@ -188,19 +272,19 @@ impl std::fmt::Display for HandlebarsRendererError {
 
 impl From<TemplateError> for HandlebarsRendererError {
     fn from(error: TemplateError) -> Self {
-        HandlebarsRendererError::TemplateError(error)
+        HandlebarsRendererError::TemplateError(Box::new(error))
     }
 }
 
 impl From<TemplateFileError> for HandlebarsRendererError {
     fn from(error: TemplateFileError) -> Self {
-        HandlebarsRendererError::TemplateFileError(error)
+        HandlebarsRendererError::TemplateFileError(Box::new(error))
     }
 }
 
 impl From<RenderError> for HandlebarsRendererError {
     fn from(error: RenderError) -> Self {
-        HandlebarsRendererError::RenderError(error)
+        HandlebarsRendererError::RenderError(Box::new(error))
     }
 }
 
@@ -209,11 +293,19 @@ struct SingleValueHolder {
     pub value: String
 }
 
+/// Wraps multiple Strings so they can be processed by handlebars. The count allows
+/// templates to choose a type by the number of values, like OneOf2 or OneOf3.
+#[derive(Serialize)]
+struct MultiValueHolder {
+    pub count: usize,
+    pub values: Vec<String>
+}
+
 #[cfg(test)]
 mod tests {
     use crate::code_generator::configuration::Configuration;
     use crate::code_generator::renderer::{Renderer, RendererImpl};
-    use crate::raw_api::type_descriptor::TypeDescriptor;
+    use crate::raw_api::type_descriptor::{SemanticType, TypeDescriptor};
 
     #[test]
     fn success_render_array() {
@@ -235,32 +327,82 @@ mod tests {
         assert_eq!(wrapped_value, format!("Option<{}>", value))
     }
 
+    #[test]
+    fn success_render_one_of() {
+        let renderer = create_renderer();
+        let values = vec![String::from("u64"), String::from("String")];
+
+        let wrapped_value = renderer.render_one_of_string(values).unwrap();
+
+        assert_eq!(wrapped_value, String::from("OneOf2<u64, String>"))
+    }
+
+    #[test]
+    fn success_render_float_without_float_type() {
+        let mut configuration = create_configuration();
+        configuration.float_type = None;
+        let renderer = RendererImpl::from_configuration(configuration).unwrap();
+
+        let rendered_type = renderer.render_type(&TypeDescriptor::Float).unwrap();
+
+        assert_eq!(rendered_type, String::from("f64"))
+    }
+
+    #[test]
+    fn success_render_one_of_without_template() {
+        let mut configuration = create_configuration();
+        configuration.one_of_type = None;
+        let renderer = RendererImpl::from_configuration(configuration).unwrap();
+        let values = vec![String::from("u64"), String::from("String")];
+
+        let wrapped_value = renderer.render_one_of_string(values).unwrap();
+
+        assert_eq!(wrapped_value, String::from("u64"))
+    }
+
     #[test]
     fn success_render_field_type() {
         let renderer = create_renderer();
         let input_expected = vec![
             (renderer.render_type(&TypeDescriptor::Integer).unwrap(), String::from("u64")),
+            (renderer.render_type(&TypeDescriptor::Integer64).unwrap(), String::from("i64")),
             (renderer.render_type(&TypeDescriptor::String).unwrap(), String::from("String")),
             (renderer.render_type(&TypeDescriptor::Boolean).unwrap(), String::from("bool")),
+            (renderer.render_type(&TypeDescriptor::True).unwrap(), String::from("True")),
+            (renderer.render_type(&TypeDescriptor::Float).unwrap(), String::from("f64")),
             (renderer.render_type(&TypeDescriptor::Optional(Box::new(TypeDescriptor::DTO(String::from("Update"))))).unwrap(), String::from("Option<Update>")),
             (renderer.render_type(&TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("Update"))))).unwrap(), String::from("Vec<Update>")),
-            (renderer.render_type(&TypeDescriptor::Optional(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("Update"))))))).unwrap(), String::from("Option<Vec<Update>>"))
+            (renderer.render_type(&TypeDescriptor::Optional(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::DTO(String::from("Update"))))))).unwrap(), String::from("Option<Vec<Update>>")),
+            (renderer.render_type(&TypeDescriptor::OneOf(vec![TypeDescriptor::Integer, TypeDescriptor::String])).unwrap(), String::from("OneOf2<u64, String>")),
+            (renderer.render_type(&TypeDescriptor::Semantic(SemanticType::UnixTime, Box::new(TypeDescriptor::Integer))).unwrap(), String::from("DateTime<Utc>")),
+            (renderer.render_type(&TypeDescriptor::Semantic(SemanticType::Url, Box::new(TypeDescriptor::String))).unwrap(), String::from("String"))
         ];
 
         input_expected.into_iter().for_each(|(input, expected)| assert_eq!(input, expected));
     }
 
     fn create_renderer() -> RendererImpl<'static> {
-        let configuration = Configuration {
+        RendererImpl::from_configuration(create_configuration()).unwrap()
+    }
+
+    fn create_configuration() -> Configuration {
+        Configuration {
             integer_type: String::from("u64"),
+            integer64_type: Some(String::from("i64")),
             string_type: String::from("String"),
             boolean_type: String::from("bool"),
+            true_type: Some(String::from("True")),
+            float_type: Some(String::from("f64")),
+            timestamp_type: Some(String::from("DateTime<Utc>")),
+            url_type: None,
+            file_id_type: None,
             array_type: String::from("Vec<{{{value}}}>"),
             optional_type: String::from("Option<{{{value}}}>"),
+            one_of_type: Some(String::from("OneOf{{count}}<{{#each values}}{{{this}}}{{#unless @last}}, {{/unless}}{{/each}}>")),
             renames: Vec::new(),
+            external_types: Vec::new(),
+            overlay_file: None,
             template_files: Vec::new()
-        };
-
-        RendererImpl::from_configuration(configuration).unwrap()
+        }
     }
 }
\ No newline at end of file
@@ -2,14 +2,18 @@ use core::fmt;
 use std::convert::TryFrom;
 use std::fmt::Formatter;
 
-use crate::code_generator::resolve_strategy::ResolveStrategy::{ForAllDTOs, ForEachDTO, ForAllMethods, ForEachMethod};
+use crate::code_generator::resolve_strategy::ResolveStrategy::{ForAllDTOs, ForEachDTO, ForAllMethods, ForEachMethod, ForAllUnions, ForEachUnion, ForAllCategories, ForEachCategory};
 
 /// Represents how a template-file should be processed.
 pub enum ResolveStrategy {
     ForAllDTOs,
     ForEachDTO,
     ForAllMethods,
-    ForEachMethod
+    ForEachMethod,
+    ForAllUnions,
+    ForEachUnion,
+    ForAllCategories,
+    ForEachCategory
 }
 
 impl TryFrom<&String> for ResolveStrategy {
@@ -21,6 +25,10 @@ impl TryFrom<&String> for ResolveStrategy {
             "FOR_EACH_DTO" => Ok(ForEachDTO),
             "FOR_ALL_METHODS" => Ok(ForAllMethods),
             "FOR_EACH_METHOD" => Ok(ForEachMethod),
+            "FOR_ALL_UNIONS" => Ok(ForAllUnions),
+            "FOR_EACH_UNION" => Ok(ForEachUnion),
+            "FOR_ALL_CATEGORIES" => Ok(ForAllCategories),
+            "FOR_EACH_CATEGORY" => Ok(ForEachCategory),
             _ => Err(NoValidResolveStrategyError { value: value.clone() })
         }
     }
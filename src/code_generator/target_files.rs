@@ -3,7 +3,7 @@ use std::collections::hash_set::{IntoIter, Iter};
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
 
-use serde::export::Formatter;
+use std::fmt::Formatter;
 
 use crate::code_generator::target_files::SameFilenameError::{Multiple, Single};
 
@@ -14,6 +14,7 @@ pub struct TargetFiles {
 }
 
 impl TargetFiles {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         TargetFiles {
             target_files_set: HashSet::new()
@@ -50,10 +51,11 @@ impl TargetFiles {
         self.target_files_set.contains(target_file)
     }
 
-    pub fn iter(&self) -> Iter<TargetFile> {
+    pub fn iter(&self) -> Iter<'_, TargetFile> {
         self.target_files_set.iter()
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn into_iter(self) -> IntoIter<TargetFile> {
         self.target_files_set.into_iter()
     }
@@ -22,7 +22,7 @@ impl CodeWriter {
             }
 
             let mut file = File::create(target_path)?;
-            file.write(target_file.content.as_bytes())?;
+            file.write_all(target_file.content.as_bytes())?;
         }
 
         Ok(())
@@ -32,10 +32,10 @@ impl CodeWriter {
         let generated_path = Path::new(Self::BASE_PATH);
 
         if generated_path.exists() {
-            remove_dir_all(&generated_path)?
+            remove_dir_all(generated_path)?
         }
 
-        create_dir(&generated_path)?;
+        create_dir(generated_path)?;
 
         Ok(())
     }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Restrictions of a field or parameter as mentioned in its description,
+/// like "1-4096 characters" or "Defaults to 100".
+#[derive(Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct Constraints {
+    pub min_length: Option<u64>,
+    pub max_length: Option<u64>,
+    pub min_value: Option<i64>,
+    pub max_value: Option<i64>,
+    pub default_value: Option<String>,
+}
\ No newline at end of file
@@ -1,18 +1,108 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
 use crate::raw_api::raw_dto::RawDto;
 use crate::raw_api::raw_method::RawMethod;
+use crate::raw_api::raw_union::RawUnion;
 
 pub mod raw_dto;
 pub mod raw_field;
 pub mod type_descriptor;
 pub mod raw_method;
 pub mod raw_parameter;
+pub mod requirement;
+pub mod raw_union;
+pub mod constraints;
+pub mod validator;
+pub mod raw_api_reader;
+pub mod overlay;
 
 pub type RawDtos = Vec<RawDto>;
 pub type RawMethods = Vec<RawMethod>;
+pub type RawUnions = Vec<RawUnion>;
+
+/// The version of the JSON format of the RawApi, see docs/raw_api_schema.md. It is increased
+/// whenever the format changes in a way that is not backwards compatible.
+pub const RAW_API_SCHEMA_VERSION: u32 = 1;
 
 /// Represents a collection of all extracted values from the HTML-API
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RawApi {
+    #[serde(rename = "dtos")]
     pub raw_dtos: RawDtos,
-    pub raw_methods: RawMethods
+    #[serde(rename = "methods")]
+    pub raw_methods: RawMethods,
+    #[serde(rename = "unions")]
+    pub raw_unions: RawUnions,
+    /// The sections of the api doc in the order they appear, used to order the categories like the doc.
+    #[serde(default)]
+    pub categories: Vec<String>
+}
+
+impl RawApi {
+    const INPUT_FILE_STR: &'static str = "InputFile";
+
+    /// Set is_file of all parameters and requires_multipart of all methods from their types. Both
+    /// are derived values, so they are updated whenever the types may have changed: after parsing,
+    /// after reading a dump and after applying an overlay.
+    pub fn update_file_flags(&mut self) {
+        let file_dto_names = self.find_file_dto_names();
+
+        for raw_method in &mut self.raw_methods {
+            for raw_parameter in &mut raw_method.parameters {
+                raw_parameter.is_file = raw_parameter.parameter_type.get_dto_names().iter().any(|dto_name| dto_name == Self::INPUT_FILE_STR);
+            }
+            raw_method.requires_multipart = raw_method.parameters.iter()
+                .flat_map(|raw_parameter| raw_parameter.parameter_type.get_dto_names())
+                .any(|dto_name| file_dto_names.contains(&dto_name));
+        }
+    }
+
+    /// Get the names of InputFile and all DTOs and unions which contain an InputFile, directly or
+    /// through other DTOs (like InputMediaVideo with its thumb or the union InputMedia).
+    fn find_file_dto_names(&self) -> HashSet<String> {
+        let mut file_dto_names = HashSet::new();
+        file_dto_names.insert(String::from(Self::INPUT_FILE_STR));
+
+        loop {
+            let mut new_file_dto_names = Vec::new();
+            for raw_dto in &self.raw_dtos {
+                let contains_file = raw_dto.fields.iter()
+                    .flat_map(|field| field.field_type.get_dto_names())
+                    .any(|dto_name| file_dto_names.contains(&dto_name));
+                if contains_file && !file_dto_names.contains(&raw_dto.name) {
+                    new_file_dto_names.push(raw_dto.name.clone())
+                }
+            }
+            for raw_union in &self.raw_unions {
+                let contains_file = raw_union.members.iter().any(|member| file_dto_names.contains(member));
+                if contains_file && !file_dto_names.contains(&raw_union.name) {
+                    new_file_dto_names.push(raw_union.name.clone())
+                }
+            }
+
+            if new_file_dto_names.is_empty() {
+                return file_dto_names;
+            }
+            file_dto_names.extend(new_file_dto_names);
+        }
+    }
+}
+
+/// The RawApi together with the version of its JSON format.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RawApiDocument {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub raw_api: RawApi
+}
+
+impl RawApiDocument {
+    pub fn new(raw_api: RawApi) -> Self {
+        RawApiDocument {
+            schema_version: RAW_API_SCHEMA_VERSION,
+            raw_api
+        }
+    }
 }
\ No newline at end of file
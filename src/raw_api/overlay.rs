@@ -0,0 +1,425 @@
+use std::fmt::Formatter;
+use std::fs::File;
+use std::io::Read;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::api_parser::description_parser::DescriptionParser;
+use crate::raw_api::RawApi;
+use crate::raw_api::constraints::Constraints;
+use crate::raw_api::raw_dto::RawDto;
+use crate::raw_api::raw_field::RawField;
+use crate::raw_api::raw_method::RawMethod;
+use crate::raw_api::raw_parameter::RawParameter;
+use crate::raw_api::raw_union::RawUnion;
+use crate::raw_api::requirement::Requirement;
+use crate::raw_api::type_descriptor::TypeDescriptor;
+
+/// A list of patches that correct mistakes of the api doc. They are applied to the
+/// parsed api before the code is generated, so the corrections survive a regeneration.
+#[derive(Deserialize, Debug)]
+pub struct Overlay {
+    pub patches: Vec<Patch>,
+}
+
+/// A single correction. DTOs, fields, methods, parameters and unions are found by their name.
+/// The modify patches only change the values that are set. The return type of a method is removed by setting it to null.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Patch {
+    AddDto { dto: RawDto },
+    RemoveDto { dto: String },
+    ModifyDto { dto: String, description: Option<String> },
+    AddField { dto: String, field: RawField },
+    RemoveField { dto: String, field: String },
+    ModifyField { dto: String, field: String, field_type: Option<TypeDescriptor>, description: Option<String> },
+    AddMethod { method: RawMethod },
+    RemoveMethod { method: String },
+    ModifyMethod {
+        method: String,
+        #[serde(default, deserialize_with = "deserialize_set_value")]
+        return_type: Option<Option<TypeDescriptor>>,
+        description: Option<String>,
+    },
+    AddParameter { method: String, parameter: RawParameter },
+    RemoveParameter { method: String, parameter: String },
+    ModifyParameter { method: String, parameter: String, parameter_type: Option<TypeDescriptor>, requirement: Option<Requirement>, description: Option<String> },
+    AddUnion { union: RawUnion },
+    RemoveUnion { union: String },
+    ModifyUnion { union: String, members: Option<Vec<String>>, description: Option<String> },
+}
+
+impl Overlay {
+    pub fn read(path: &str) -> Result<Self, OverlayError> {
+        Self::read_from(File::open(path)?)
+    }
+
+    pub fn read_from<R: Read>(reader: R) -> Result<Self, OverlayError> {
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Apply all patches to the api. Every patch must match: a patch that adds something must not find
+    /// an existing entry with the same name, all other patches must find the entry they change.
+    /// The patches which did not match are returned as an error, after all others were applied.
+    ///
+    /// The values the api parser derives from the types and descriptions (like the allowed values,
+    /// the semantic types and the file flags) are derived again for the patched entries.
+    pub fn apply<D: DescriptionParser>(self, raw_api: &mut RawApi, description_parser: &D) -> Result<(), OverlayError> {
+        let mut unmatched_patches = Vec::new();
+
+        for patch in self.patches {
+            let description = patch.to_string();
+            if !patch.apply(raw_api, description_parser) {
+                unmatched_patches.push(description)
+            }
+        }
+        raw_api.update_file_flags();
+
+        match unmatched_patches.is_empty() {
+            true => Ok(()),
+            false => Err(OverlayError::UnmatchedPatches(unmatched_patches))
+        }
+    }
+}
+
+impl Patch {
+    /// Apply the patch and tell if it matched.
+    fn apply<D: DescriptionParser>(self, raw_api: &mut RawApi, description_parser: &D) -> bool {
+        match self {
+            Patch::AddDto { dto } => Self::add(&mut raw_api.raw_dtos, dto, |raw_dto| &raw_dto.name),
+            Patch::RemoveDto { dto } => Self::remove(&mut raw_api.raw_dtos, &dto, |raw_dto| &raw_dto.name),
+            Patch::ModifyDto { dto, description } => Self::modify(&mut raw_api.raw_dtos, &dto, |raw_dto| &raw_dto.name, |raw_dto| {
+                Self::replace(&mut raw_dto.description, description);
+                true
+            }),
+            Patch::AddField { dto, mut field } => Self::modify(&mut raw_api.raw_dtos, &dto, |raw_dto| &raw_dto.name, |raw_dto| {
+                Self::derive_field_values(&mut field, description_parser);
+                Self::add(&mut raw_dto.fields, field, |raw_field| &raw_field.name)
+            }),
+            Patch::RemoveField { dto, field } => Self::modify(&mut raw_api.raw_dtos, &dto, |raw_dto| &raw_dto.name, |raw_dto| {
+                Self::remove(&mut raw_dto.fields, &field, |raw_field| &raw_field.name)
+            }),
+            Patch::ModifyField { dto, field, field_type, description } => Self::modify(&mut raw_api.raw_dtos, &dto, |raw_dto| &raw_dto.name, |raw_dto| {
+                Self::modify(&mut raw_dto.fields, &field, |raw_field| &raw_field.name, |raw_field| {
+                    if field_type.is_none() && description.is_none() {
+                        return true;
+                    }
+                    raw_field.field_type = field_type.unwrap_or_else(|| raw_field.field_type.without_semantic());
+                    Self::replace(&mut raw_field.description, description);
+                    raw_field.allowed_values = Vec::new();
+                    raw_field.discriminator_value = None;
+                    raw_field.constraints = Constraints::default();
+                    Self::derive_field_values(raw_field, description_parser);
+                    true
+                })
+            }),
+            Patch::AddMethod { method } => Self::add(&mut raw_api.raw_methods, method, |raw_method| &raw_method.name),
+            Patch::RemoveMethod { method } => Self::remove(&mut raw_api.raw_methods, &method, |raw_method| &raw_method.name),
+            Patch::ModifyMethod { method, return_type, description } => Self::modify(&mut raw_api.raw_methods, &method, |raw_method| &raw_method.name, |raw_method| {
+                Self::replace(&mut raw_method.return_type, return_type);
+                Self::replace(&mut raw_method.description, description);
+                true
+            }),
+            Patch::AddParameter { method, mut parameter } => Self::modify(&mut raw_api.raw_methods, &method, |raw_method| &raw_method.name, |raw_method| {
+                Self::derive_parameter_values(&mut parameter, description_parser);
+                Self::add(&mut raw_method.parameters, parameter, |raw_parameter| &raw_parameter.name)
+            }),
+            Patch::RemoveParameter { method, parameter } => Self::modify(&mut raw_api.raw_methods, &method, |raw_method| &raw_method.name, |raw_method| {
+                Self::remove(&mut raw_method.parameters, &parameter, |raw_parameter| &raw_parameter.name)
+            }),
+            Patch::ModifyParameter { method, parameter, parameter_type, requirement, description } => Self::modify(&mut raw_api.raw_methods, &method, |raw_method| &raw_method.name, |raw_method| {
+                Self::modify(&mut raw_method.parameters, &parameter, |raw_parameter| &raw_parameter.name, |raw_parameter| {
+                    if parameter_type.is_none() && requirement.is_none() && description.is_none() {
+                        return true;
+                    }
+                    let parameter_type = parameter_type.unwrap_or_else(|| raw_parameter.parameter_type.without_semantic());
+                    let requirement = requirement.unwrap_or_else(|| match (parameter_type.is_optional(), &raw_parameter.requirement) {
+                        (true, _) => Requirement::Optional,
+                        (false, Requirement::Optional) => Requirement::Required,
+                        (false, requirement) => requirement.clone()
+                    });
+                    raw_parameter.parameter_type = Self::match_requirement(parameter_type, &requirement);
+                    raw_parameter.requirement = requirement;
+                    Self::replace(&mut raw_parameter.description, description);
+                    raw_parameter.allowed_values = Vec::new();
+                    raw_parameter.constraints = Constraints::default();
+                    raw_parameter.json_serialized = false;
+                    Self::derive_parameter_values(raw_parameter, description_parser);
+                    true
+                })
+            }),
+            Patch::AddUnion { union } => Self::add(&mut raw_api.raw_unions, union, |raw_union| &raw_union.name),
+            Patch::RemoveUnion { union } => Self::remove(&mut raw_api.raw_unions, &union, |raw_union| &raw_union.name),
+            Patch::ModifyUnion { union, members, description } => Self::modify(&mut raw_api.raw_unions, &union, |raw_union| &raw_union.name, |raw_union| {
+                Self::replace(&mut raw_union.members, members);
+                Self::replace(&mut raw_union.description, description);
+                true
+            }),
+        }
+    }
+
+    fn add<T>(entries: &mut Vec<T>, entry: T, get_name: fn(&T) -> &String) -> bool {
+        if entries.iter().any(|existing| get_name(existing) == get_name(&entry)) {
+            return false;
+        }
+        entries.push(entry);
+        true
+    }
+
+    fn remove<T>(entries: &mut Vec<T>, name: &str, get_name: fn(&T) -> &String) -> bool {
+        let length = entries.len();
+        entries.retain(|entry| get_name(entry) != name);
+        entries.len() != length
+    }
+
+    fn modify<T, F: FnOnce(&mut T) -> bool>(entries: &mut [T], name: &str, get_name: fn(&T) -> &String, modification: F) -> bool {
+        match entries.iter_mut().find(|entry| get_name(entry) == name) {
+            Some(entry) => modification(entry),
+            None => false
+        }
+    }
+
+    fn replace<T>(value: &mut T, new_value: Option<T>) {
+        if let Some(new_value) = new_value {
+            *value = new_value
+        }
+    }
+
+    /// Wrap the type of a parameter into an Optional or take it out of one, so it matches the requirement.
+    fn match_requirement(parameter_type: TypeDescriptor, requirement: &Requirement) -> TypeDescriptor {
+        let base_type = match parameter_type {
+            TypeDescriptor::Optional(optional_type) => *optional_type,
+            parameter_type => parameter_type
+        };
+        match requirement.is_required() {
+            true => base_type,
+            false => TypeDescriptor::Optional(Box::new(base_type))
+        }
+    }
+
+    /// Derive the values of a field from its type and description like the api parser does.
+    /// Values that are already set are kept, so a patch can still set them explicitly.
+    fn derive_field_values<D: DescriptionParser>(raw_field: &mut RawField, description_parser: &D) {
+        let base_type = raw_field.field_type.without_semantic();
+        if raw_field.allowed_values.is_empty() {
+            raw_field.allowed_values = description_parser.parse_allowed_values(&base_type, &raw_field.description);
+        }
+        if raw_field.discriminator_value.is_none() {
+            raw_field.discriminator_value = description_parser.parse_discriminator_value(&base_type, &raw_field.description);
+        }
+        if raw_field.constraints == Constraints::default() {
+            raw_field.constraints = description_parser.parse_constraints(&base_type, &raw_field.description);
+        }
+        if raw_field.field_type == base_type {
+            raw_field.field_type = description_parser.parse_semantic_type(&raw_field.name, base_type, &raw_field.description);
+        }
+    }
+
+    /// Derive the values of a parameter from its type and description like the api parser does.
+    /// Values that are already set are kept. The file flags are updated for the whole api at the end.
+    fn derive_parameter_values<D: DescriptionParser>(raw_parameter: &mut RawParameter, description_parser: &D) {
+        let base_type = raw_parameter.parameter_type.without_semantic();
+        if raw_parameter.allowed_values.is_empty() {
+            raw_parameter.allowed_values = description_parser.parse_allowed_values(&base_type, &raw_parameter.description);
+        }
+        if raw_parameter.constraints == Constraints::default() {
+            raw_parameter.constraints = description_parser.parse_constraints(&base_type, &raw_parameter.description);
+        }
+        if !raw_parameter.json_serialized {
+            raw_parameter.json_serialized = description_parser.parse_json_serialized(&raw_parameter.description);
+        }
+        if raw_parameter.parameter_type == base_type {
+            raw_parameter.parameter_type = description_parser.parse_semantic_type(&raw_parameter.name, base_type, &raw_parameter.description);
+        }
+    }
+}
+
+/// Deserialize a value that is present, so a null becomes Some(None) and can be told apart from a missing value.
+fn deserialize_set_value<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+impl std::fmt::Display for Patch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Patch::AddDto { dto } => write!(f, "add_dto {}", dto.name),
+            Patch::RemoveDto { dto } => write!(f, "remove_dto {}", dto),
+            Patch::ModifyDto { dto, .. } => write!(f, "modify_dto {}", dto),
+            Patch::AddField { dto, field } => write!(f, "add_field {}.{}", dto, field.name),
+            Patch::RemoveField { dto, field } => write!(f, "remove_field {}.{}", dto, field),
+            Patch::ModifyField { dto, field, .. } => write!(f, "modify_field {}.{}", dto, field),
+            Patch::AddMethod { method } => write!(f, "add_method {}", method.name),
+            Patch::RemoveMethod { method } => write!(f, "remove_method {}", method),
+            Patch::ModifyMethod { method, .. } => write!(f, "modify_method {}", method),
+            Patch::AddParameter { method, parameter } => write!(f, "add_parameter {}.{}", method, parameter.name),
+            Patch::RemoveParameter { method, parameter } => write!(f, "remove_parameter {}.{}", method, parameter),
+            Patch::ModifyParameter { method, parameter, .. } => write!(f, "modify_parameter {}.{}", method, parameter),
+            Patch::AddUnion { union } => write!(f, "add_union {}", union.name),
+            Patch::RemoveUnion { union } => write!(f, "remove_union {}", union),
+            Patch::ModifyUnion { union, .. } => write!(f, "modify_union {}", union),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum OverlayError {
+    OpenFileError(std::io::Error),
+    DeserializeJsonError(serde_json::Error),
+    UnmatchedPatches(Vec<String>),
+}
+
+impl std::error::Error for OverlayError {}
+
+impl std::fmt::Display for OverlayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OverlayError::OpenFileError(error) => std::io::Error::fmt(error, f),
+            OverlayError::DeserializeJsonError(error) => serde_json::Error::fmt(error, f),
+            OverlayError::UnmatchedPatches(patches) => {
+                writeln!(f, "The following patches of the overlay don't match the api anymore. Maybe the api doc was fixed?")?;
+                for patch in patches {
+                    writeln!(f, "{}", patch)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for OverlayError {
+    fn from(error: std::io::Error) -> Self {
+        OverlayError::OpenFileError(error)
+    }
+}
+
+impl From<serde_json::Error> for OverlayError {
+    fn from(error: serde_json::Error) -> Self {
+        OverlayError::DeserializeJsonError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api_parser::description_parser::DescriptionParserImpl;
+    use crate::raw_api::RawApi;
+    use crate::raw_api::constraints::Constraints;
+    use crate::raw_api::overlay::{Overlay, OverlayError};
+    use crate::raw_api::raw_dto::RawDto;
+    use crate::raw_api::raw_field::RawField;
+    use crate::raw_api::raw_method::RawMethod;
+    use crate::raw_api::raw_parameter::RawParameter;
+    use crate::raw_api::requirement::Requirement;
+    use crate::raw_api::type_descriptor::{SemanticType, TypeDescriptor};
+
+    fn create_raw_api() -> RawApi {
+        let field = RawField::new(String::from("id"), TypeDescriptor::String, String::from("The id."), Vec::new(), None, Constraints::default());
+
+        RawApi {
+            raw_dtos: vec![RawDto::new(String::from("User"), String::from("A user."), vec![field])],
+            raw_methods: Vec::new(),
+            raw_unions: Vec::new(),
+            categories: Vec::new()
+        }
+    }
+
+    #[test]
+    fn success_apply() {
+        let json = r#"{"patches": [
+            {"action": "modify_field", "dto": "User", "field": "id", "field_type": {"kind": "integer64"}},
+            {"action": "add_field", "dto": "User", "field": {"name": "name", "field_type": {"kind": "string"}, "description": "The name."}},
+            {"action": "add_dto", "dto": {"name": "Chat", "description": "A chat.", "fields": []}}
+        ]}"#;
+        let mut raw_api = create_raw_api();
+
+        Overlay::read_from(json.as_bytes()).unwrap().apply(&mut raw_api, &DescriptionParserImpl::new()).unwrap();
+
+        assert_eq!(raw_api.raw_dtos[0].fields[0].field_type, TypeDescriptor::Integer64);
+        assert_eq!(raw_api.raw_dtos[0].fields[0].description, String::from("The id."));
+        assert_eq!(raw_api.raw_dtos[0].fields[1].name, String::from("name"));
+        assert_eq!(raw_api.raw_dtos[1].name, String::from("Chat"))
+    }
+
+    #[test]
+    fn success_apply_derives_values() {
+        let json = r#"{"patches": [
+            {"action": "modify_field", "dto": "User", "field": "id", "description": "The id. Unix time"},
+            {"action": "add_field", "dto": "User", "field": {"name": "type", "field_type": {"kind": "string"}, "description": "Type of the user, must be bot"}},
+            {"action": "modify_parameter", "method": "sendPhoto", "parameter": "photo", "parameter_type": {"kind": "dto", "value": "InputFile"}},
+            {"action": "add_parameter", "method": "sendPhoto", "parameter": {"name": "parse_mode", "parameter_type": {"kind": "string"}, "description": "Send Markdown or HTML, if you want Telegram apps to show bold text."}}
+        ]}"#;
+        let mut raw_api = create_raw_api();
+        raw_api.raw_dtos[0].fields[0].field_type = TypeDescriptor::Integer;
+        let photo = RawParameter {
+            name: String::from("photo"),
+            parameter_type: TypeDescriptor::String,
+            requirement: Requirement::Required,
+            description: String::from("The photo."),
+            allowed_values: Vec::new(),
+            constraints: Constraints::default(),
+            is_file: false,
+            json_serialized: false
+        };
+        raw_api.raw_methods.push(RawMethod { name: String::from("sendPhoto"), description: String::from("Sends a photo."), category: None, parameters: vec![photo], return_type: None, requires_multipart: false });
+
+        Overlay::read_from(json.as_bytes()).unwrap().apply(&mut raw_api, &DescriptionParserImpl::new()).unwrap();
+
+        let fields = &raw_api.raw_dtos[0].fields;
+        assert_eq!(fields[0].field_type, TypeDescriptor::Semantic(SemanticType::UnixTime, Box::new(TypeDescriptor::Integer)));
+        assert_eq!(fields[1].discriminator_value, Some(String::from("bot")));
+        let raw_method = &raw_api.raw_methods[0];
+        assert!(raw_method.parameters[0].is_file);
+        assert!(raw_method.requires_multipart);
+        assert_eq!(raw_method.parameters[1].allowed_values, vec![String::from("Markdown"), String::from("HTML")])
+    }
+
+    #[test]
+    fn success_apply_requirement_and_return_type() {
+        let json = r#"{"patches": [
+            {"action": "modify_parameter", "method": "sendPhoto", "parameter": "photo", "requirement": {"kind": "optional"}},
+            {"action": "modify_parameter", "method": "sendPhoto", "parameter": "caption", "parameter_type": {"kind": "string"}},
+            {"action": "modify_method", "method": "sendPhoto", "return_type": null}
+        ]}"#;
+        let mut raw_api = create_raw_api();
+        let create_parameter = |name: &str, parameter_type: TypeDescriptor, requirement: Requirement| RawParameter {
+            name: String::from(name),
+            parameter_type,
+            requirement,
+            description: String::new(),
+            allowed_values: Vec::new(),
+            constraints: Constraints::default(),
+            is_file: false,
+            json_serialized: false
+        };
+        let parameters = vec![
+            create_parameter("photo", TypeDescriptor::String, Requirement::Required),
+            create_parameter("caption", TypeDescriptor::Optional(Box::new(TypeDescriptor::String)), Requirement::Optional)
+        ];
+        raw_api.raw_methods.push(RawMethod { name: String::from("sendPhoto"), description: String::from("Sends a photo."), category: None, parameters, return_type: Some(TypeDescriptor::Boolean), requires_multipart: false });
+
+        Overlay::read_from(json.as_bytes()).unwrap().apply(&mut raw_api, &DescriptionParserImpl::new()).unwrap();
+
+        let raw_method = &raw_api.raw_methods[0];
+        assert_eq!(raw_method.parameters[0].requirement, Requirement::Optional);
+        assert_eq!(raw_method.parameters[0].parameter_type, TypeDescriptor::Optional(Box::new(TypeDescriptor::String)));
+        assert_eq!(raw_method.parameters[1].requirement, Requirement::Required);
+        assert_eq!(raw_method.parameters[1].parameter_type, TypeDescriptor::String);
+        assert_eq!(raw_method.return_type, None)
+    }
+
+    #[test]
+    fn failure_unmatched_patches() {
+        let json = r#"{"patches": [
+            {"action": "remove_field", "dto": "User", "field": "username"},
+            {"action": "remove_dto", "dto": "User"},
+            {"action": "add_dto", "dto": {"name": "User", "description": "A user.", "fields": []}},
+            {"action": "remove_method", "method": "getMe"}
+        ]}"#;
+        let mut raw_api = create_raw_api();
+
+        match Overlay::read_from(json.as_bytes()).unwrap().apply(&mut raw_api, &DescriptionParserImpl::new()) {
+            Err(OverlayError::UnmatchedPatches(patches)) => assert_eq!(patches, vec![String::from("remove_field User.username"), String::from("remove_method getMe")]),
+            result => panic!("Unexpected result: {:?}", result)
+        }
+        assert_eq!(raw_api.raw_dtos[0].fields.len(), 0)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,109 @@
+use std::fs::File;
+use std::fmt::Formatter;
+use std::io::Read;
+
+use crate::raw_api::{RawApi, RawApiDocument, RAW_API_SCHEMA_VERSION};
+
+/// Reads a RawApi from the JSON written by the dump command, see docs/raw_api_schema.md.
+/// The derived file flags are recomputed, so an edited dump can't contradict its types.
+pub struct RawApiReader;
+
+impl RawApiReader {
+    pub fn read(&self, path: &str) -> Result<RawApi, RawApiReadError> {
+        self.read_from(File::open(path)?)
+    }
+
+    pub fn read_from<R: Read>(&self, reader: R) -> Result<RawApi, RawApiReadError> {
+        let document: RawApiDocument = serde_json::from_reader(reader)?;
+
+        if document.schema_version != RAW_API_SCHEMA_VERSION {
+            return Err(RawApiReadError::UnsupportedSchemaVersion(document.schema_version));
+        }
+
+        let mut raw_api = document.raw_api;
+        raw_api.update_file_flags();
+        Ok(raw_api)
+    }
+}
+
+#[derive(Debug)]
+pub enum RawApiReadError {
+    OpenFileError(std::io::Error),
+    DeserializeJsonError(serde_json::Error),
+    UnsupportedSchemaVersion(u32),
+}
+
+impl std::error::Error for RawApiReadError {}
+
+impl std::fmt::Display for RawApiReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RawApiReadError::OpenFileError(error) => std::io::Error::fmt(error, f),
+            RawApiReadError::DeserializeJsonError(error) => serde_json::Error::fmt(error, f),
+            RawApiReadError::UnsupportedSchemaVersion(schema_version) => writeln!(f, "The schema version {} is not supported, only version {} can be read.", schema_version, RAW_API_SCHEMA_VERSION)
+        }
+    }
+}
+
+impl From<std::io::Error> for RawApiReadError {
+    fn from(error: std::io::Error) -> Self {
+        RawApiReadError::OpenFileError(error)
+    }
+}
+
+impl From<serde_json::Error> for RawApiReadError {
+    fn from(error: serde_json::Error) -> Self {
+        RawApiReadError::DeserializeJsonError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::raw_api::raw_api_reader::{RawApiReader, RawApiReadError};
+    use crate::raw_api::type_descriptor::TypeDescriptor;
+
+    #[test]
+    fn success_read() {
+        let json = r#"{
+            "schema_version": 1,
+            "dtos": [{"name": "User", "description": "A user.", "fields": [{"name": "id", "field_type": {"kind": "integer"}, "description": "The id."}]}],
+            "methods": [{"name": "getMe", "description": "Returns the bot.", "parameters": [], "return_type": {"kind": "dto", "value": "User"}}],
+            "unions": []
+        }"#;
+
+        let raw_api = RawApiReader.read_from(json.as_bytes()).unwrap();
+
+        assert_eq!(raw_api.raw_dtos[0].fields[0].field_type, TypeDescriptor::Integer);
+        assert_eq!(raw_api.raw_methods[0].return_type, Some(TypeDescriptor::DTO(String::from("User"))));
+        assert!(!raw_api.raw_methods[0].requires_multipart)
+    }
+
+    #[test]
+    fn success_read_recomputes_file_flags() {
+        let json = r#"{
+            "schema_version": 1,
+            "dtos": [{"name": "InputFile", "description": "A file.", "fields": []}],
+            "methods": [{"name": "sendPhoto", "description": "Sends a photo.", "parameters": [
+                {"name": "photo", "parameter_type": {"kind": "dto", "value": "InputFile"}, "description": "The photo."},
+                {"name": "caption", "parameter_type": {"kind": "string"}, "description": "The caption.", "is_file": true}
+            ], "return_type": null, "requires_multipart": false}],
+            "unions": []
+        }"#;
+
+        let raw_api = RawApiReader.read_from(json.as_bytes()).unwrap();
+
+        let is_file: Vec<bool> = raw_api.raw_methods[0].parameters.iter().map(|raw_parameter| raw_parameter.is_file).collect();
+        assert_eq!(is_file, vec![true, false]);
+        assert!(raw_api.raw_methods[0].requires_multipart)
+    }
+
+    #[test]
+    fn failure_unsupported_schema_version() {
+        let json = r#"{"schema_version": 2, "dtos": [], "methods": [], "unions": []}"#;
+
+        match RawApiReader.read_from(json.as_bytes()) {
+            Err(RawApiReadError::UnsupportedSchemaVersion(2)) => (),
+            result => panic!("Unexpected result: {:?}", result)
+        }
+    }
+}
\ No newline at end of file
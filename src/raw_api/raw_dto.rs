@@ -1,16 +1,24 @@
+use serde::{Deserialize, Serialize};
+
 use crate::raw_api::raw_field::RawField;
 
-/// Holds a DTO with its name and all fields.
-#[derive(Eq,PartialEq ,Debug)]
+/// Holds a DTO with its name, description and all fields.
+#[derive(Eq,PartialEq ,Debug, Serialize, Deserialize)]
 pub struct RawDto {
     pub name: String,
+    pub description: String,
+    /// The section of the api doc (like "Stickers") the DTO is defined in.
+    #[serde(default)]
+    pub category: Option<String>,
     pub fields: Vec<RawField>
 }
 
 impl RawDto {
-    pub fn new(name: String, fields: Vec<RawField>) -> Self {
+    pub fn new(name: String, description: String, fields: Vec<RawField>) -> Self {
         RawDto {
             name,
+            description,
+            category: None,
             fields
         }
     }
@@ -1,32 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::raw_api::constraints::Constraints;
 use crate::raw_api::type_descriptor::TypeDescriptor;
 
-/// Struct of a DTO with name and type
-#[derive(Eq, PartialEq, Debug)]
+/// Struct of a DTO with name, type, description, the values a String field is restricted to, the constant value of a discriminator field and its constraints
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct RawField {
     pub name: String,
     pub field_type: TypeDescriptor,
+    pub description: String,
+    #[serde(default)]
+    pub allowed_values: Vec<String>,
+    #[serde(default)]
+    pub discriminator_value: Option<String>,
+    #[serde(default)]
+    pub constraints: Constraints,
 }
 
 impl RawField {
-    pub fn new(name: String, type_value: String, optional: bool) -> Self {
+    pub fn new(name: String, field_type: TypeDescriptor, description: String, allowed_values: Vec<String>, discriminator_value: Option<String>, constraints: Constraints) -> Self {
         RawField {
             name,
-            field_type: TypeDescriptor::from(FieldDescription { value: type_value, optional })
-        }
-    }
-}
-
-#[derive(Clone)]
-pub struct FieldDescription {
-    pub value: String,
-    pub optional: bool,
-}
-
-impl FieldDescription {
-    pub fn new(value: String, optional: bool) -> Self {
-        FieldDescription {
-            value,
-            optional
+            field_type,
+            description,
+            allowed_values,
+            discriminator_value,
+            constraints
         }
     }
 }
\ No newline at end of file
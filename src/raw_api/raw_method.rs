@@ -1,7 +1,18 @@
+use serde::{Deserialize, Serialize};
+
 use crate::raw_api::raw_parameter::RawParameter;
+use crate::raw_api::type_descriptor::TypeDescriptor;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RawMethod {
     pub name: String,
-    pub parameters: Vec<RawParameter>
-}
\ No newline at end of file
+    pub description: String,
+    /// The section of the api doc (like "Stickers") the method is defined in.
+    #[serde(default)]
+    pub category: Option<String>,
+    pub parameters: Vec<RawParameter>,
+    pub return_type: Option<TypeDescriptor>,
+    /// True if a parameter contains an InputFile, so the method has to be sent as multipart/form-data.
+    #[serde(default)]
+    pub requires_multipart: bool
+}
@@ -1,7 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::raw_api::constraints::Constraints;
+use crate::raw_api::requirement::Requirement;
 use crate::raw_api::type_descriptor::TypeDescriptor;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RawParameter {
     pub name: String,
     pub parameter_type: TypeDescriptor,
+    /// Parameters without a requirement (like in patches of an overlay) are required.
+    #[serde(default)]
+    pub requirement: Requirement,
+    pub description: String,
+    #[serde(default)]
+    pub allowed_values: Vec<String>,
+    #[serde(default)]
+    pub constraints: Constraints,
+    #[serde(default)]
+    pub is_file: bool,
+    /// True if the value has to be sent as a JSON string, like "A JSON-serialized object" for reply_markup.
+    #[serde(default)]
+    pub json_serialized: bool,
 }
\ No newline at end of file
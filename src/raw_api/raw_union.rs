@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Holds a type which is one of several DTOs, like InputMedia.
+#[derive(Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RawUnion {
+    pub name: String,
+    pub description: String,
+    /// The section of the api doc (like "Inline mode") the union is defined in.
+    #[serde(default)]
+    pub category: Option<String>,
+    pub members: Vec<String>
+}
\ No newline at end of file
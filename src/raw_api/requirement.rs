@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// The content of the Required column of a method table.
+#[derive(Eq, PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum Requirement {
+    #[default]
+    Required,
+    Optional,
+    /// The column contains something else than "Yes" or "Optional". Such parameters are treated as required.
+    Unknown(String),
+}
+
+impl Requirement {
+    pub fn is_required(&self) -> bool {
+        *self != Requirement::Optional
+    }
+}
\ No newline at end of file
@@ -1,20 +1,39 @@
-use crate::raw_api::raw_field::FieldDescription;
+use serde::{Deserialize, Serialize};
 
-#[derive(Eq, PartialEq, Debug)]
+/// The type of a field, parameter or return value. In JSON it is written as an object with
+/// the kind of the type and, for all kinds except the primitives, a value.
+#[derive(Eq, PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
 pub enum TypeDescriptor {
     Integer,
+    /// An integer which may need more than 32 bits, like the id of a chat.
+    Integer64,
     String,
     Boolean,
+    /// A boolean which is always true, mostly used as the return type of methods.
+    True,
+    Float,
+    #[serde(rename = "dto")]
     DTO(String),
     ArrayOf(Box<TypeDescriptor>),
-    Optional(Box<TypeDescriptor>)
+    Optional(Box<TypeDescriptor>),
+    OneOf(Vec<TypeDescriptor>),
+    /// A primitive with a special meaning, like an Integer holding a unix time.
+    Semantic(SemanticType, Box<TypeDescriptor>)
+}
+
+#[derive(Eq, PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SemanticType {
+    UnixTime,
+    Url,
+    FileId
 }
 
 impl TypeDescriptor {
-    const INTEGER: &'static str = "Integer";
-    const STRING: &'static str = "String";
-    const BOOLEAN: &'static str = "Boolean";
-    const ARRAY_OF: &'static str = "Arrayof";
+    pub fn is_optional(&self) -> bool {
+        matches!(self, TypeDescriptor::Optional(_))
+    }
 
     pub fn get_dto_name(&self) -> Option<String> {
         match self {
@@ -25,149 +44,33 @@ impl TypeDescriptor {
         }
     }
 
-    fn trim_whitespace(string: &String) -> String {
-        let mut result = string.clone();
-        result.retain(|c| !c.is_whitespace());
-
-        result
-    }
-}
-
-impl From<FieldDescription> for TypeDescriptor {
-    fn from(field_description: FieldDescription) -> Self {
-        let value = field_description.value;
-
-        if field_description.optional {
-            return TypeDescriptor::Optional(Box::new(TypeDescriptor::from(FieldDescription::new(value, false))));
+    /// Get the type with the plain primitives instead of the semantic types wrapped around them.
+    pub fn without_semantic(&self) -> TypeDescriptor {
+        match self {
+            TypeDescriptor::Semantic(_, semantic_type) => semantic_type.without_semantic(),
+            TypeDescriptor::ArrayOf(array_field_type) => TypeDescriptor::ArrayOf(Box::new(array_field_type.without_semantic())),
+            TypeDescriptor::Optional(optional_field_type) => TypeDescriptor::Optional(Box::new(optional_field_type.without_semantic())),
+            TypeDescriptor::OneOf(one_of_types) => TypeDescriptor::OneOf(one_of_types.iter().map(|one_of_type| one_of_type.without_semantic()).collect()),
+            _ => self.clone()
         }
+    }
 
-        match value.as_str() {
-            TypeDescriptor::INTEGER => TypeDescriptor::Integer,
-            TypeDescriptor::STRING => TypeDescriptor::String,
-            TypeDescriptor::BOOLEAN => TypeDescriptor::Boolean,
-            _ => {
-                let trimmed = TypeDescriptor::trim_whitespace(&value);
-
-                if trimmed.starts_with(TypeDescriptor::ARRAY_OF){
-                    let result = String::from(&trimmed.as_str()[TypeDescriptor::ARRAY_OF.len()..trimmed.len()]);
-                    TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::from(FieldDescription::new(result, false))))
-                } else {
-                    TypeDescriptor::DTO(value)
-                }
-            }
+    /// Get the names of all DTOs used by this type. Unlike get_dto_name,
+    /// this also includes every possible DTO of a OneOf.
+    pub fn get_dto_names(&self) -> Vec<String> {
+        match self {
+            TypeDescriptor::DTO(dto_name) => vec![dto_name.clone()],
+            TypeDescriptor::ArrayOf(array_field_type) => array_field_type.get_dto_names(),
+            TypeDescriptor::Optional(optional_field_type) => optional_field_type.get_dto_names(),
+            TypeDescriptor::OneOf(one_of_types) => one_of_types.iter().flat_map(|one_of_type| one_of_type.get_dto_names()).collect(),
+            _ => Vec::new()
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::raw_api::raw_field::FieldDescription;
-    use crate::raw_api::type_descriptor::TypeDescriptor;
-
-    #[test]
-    fn success_integer() {
-        let input = FieldDescription::new(String::from("Integer"), false);
-        let field_type = TypeDescriptor::from(input);
-
-        match field_type {
-            TypeDescriptor::Integer => (),
-            _ => panic!("Value not parsed to Integer!")
-        }
-    }
-
-    #[test]
-    fn success_string() {
-        let input = FieldDescription::new(String::from("String"), false);
-        let field_type = TypeDescriptor::from(input);
-
-        match field_type {
-            TypeDescriptor::String => (),
-            _ => panic!("Value not parsed to String!")
-        }
-    }
-
-    #[test]
-    fn success_dto() {
-        let input = FieldDescription::new(String::from("Update"), false);
-        let field_type = TypeDescriptor::from(input.clone());
-
-        match field_type {
-            TypeDescriptor::DTO(dto_name) => {
-                assert_eq!(dto_name, input.value)
-            },
-            _ => panic!("Value not parsed to DTO!")
-        }
-    }
-
-    #[test]
-    fn success_array_of_non_dto() {
-        let mut array_of = String::from("Array of ");
-        let value = String::from("Integer");
-        array_of.push_str(value.as_str());
-
-        let field_type = TypeDescriptor::from(FieldDescription::new(array_of, false));
-
-        match field_type {
-            TypeDescriptor::ArrayOf(array_value) => {
-                match *array_value {
-                    TypeDescriptor::Integer => (),
-                    _ => panic!("Interior value not parsed to Integer!")
-                }
-            }
-            _ => panic!("Value not parsed to Array!")
-        }
-    }
-    #[test]
-    fn success_array_of_dto() {
-        let mut array_of = String::from("Array of ");
-        let value = String::from("Update");
-        array_of.push_str(value.as_str());
-
-        let field_type = TypeDescriptor::from(FieldDescription::new(array_of, false));
-
-        match field_type {
-            TypeDescriptor::ArrayOf(array_value) => {
-                match *array_value {
-                    TypeDescriptor::DTO(dto_name) => {
-                        assert_eq!(dto_name, value)
-                    }
-                    _ => panic!("Interior value not parsed to DTO!")
-                }
-            }
-            _ => panic!("Value not parsed to Array!")
-        }
-    }
-
-    #[test]
-    fn success_optional() {
-        let input = FieldDescription::new(String::from("String"), true);
-        let field_type = TypeDescriptor::from(input);
-
-        match field_type {
-            TypeDescriptor::Optional(value) => match *value {
-                TypeDescriptor::String => (),
-                _ => panic!("Value not parsed to String!")
-            },
-            _ => panic!("Value not parsed to Optional!")
-        }
-    }
-
-    #[test]
-    fn success_optional_of_array() {
-        let input = FieldDescription::new(String::from("Array of String"), true);
-        let field_type = TypeDescriptor::from(input);
-
-        match field_type {
-            TypeDescriptor::Optional(optional_value) => match *optional_value {
-                TypeDescriptor::ArrayOf(array_value) => match *array_value {
-                    TypeDescriptor::String => (),
-                    _ => panic!("Value not parsed to String!")
-                },
-                _ => panic!("Value not parsed to Array!")
-            },
-            _ => panic!("Value not parsed to Optional!")
-        }
-    }
+    use crate::raw_api::type_descriptor::{SemanticType, TypeDescriptor};
 
     #[test]
     fn success_get_dto_name_integer() {
@@ -210,4 +113,34 @@ mod tests {
 
         assert_eq!(Some(dto_name), field_type.get_dto_name())
     }
+
+    #[test]
+    fn success_get_dto_names_one_of() {
+        let field_type = TypeDescriptor::Optional(Box::new(TypeDescriptor::OneOf(vec![
+            TypeDescriptor::DTO(String::from("InlineKeyboardMarkup")),
+            TypeDescriptor::String,
+            TypeDescriptor::DTO(String::from("ForceReply"))
+        ])));
+
+        assert_eq!(vec![String::from("InlineKeyboardMarkup"), String::from("ForceReply")], field_type.get_dto_names())
+    }
+
+    #[test]
+    fn success_without_semantic() {
+        let field_type = TypeDescriptor::Optional(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::Semantic(SemanticType::UnixTime, Box::new(TypeDescriptor::Integer))))));
+
+        let expected = TypeDescriptor::Optional(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::Integer))));
+        assert_eq!(field_type.without_semantic(), expected)
+    }
+
+    #[test]
+    fn success_serialize_json() {
+        let field_type = TypeDescriptor::Optional(Box::new(TypeDescriptor::ArrayOf(Box::new(TypeDescriptor::OneOf(vec![
+            TypeDescriptor::DTO(String::from("InputMediaPhoto")),
+            TypeDescriptor::Semantic(SemanticType::Url, Box::new(TypeDescriptor::String))
+        ])))));
+
+        let expected = r#"{"kind":"optional","value":{"kind":"array_of","value":{"kind":"one_of","value":[{"kind":"dto","value":"InputMediaPhoto"},{"kind":"semantic","value":["url",{"kind":"string"}]}]}}}"#;
+        assert_eq!(serde_json::to_string(&field_type).unwrap(), expected)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+use std::fmt::Formatter;
+
+use crate::raw_api::RawApi;
+
+/// Checks that every DTO referenced by the api is defined as a DTO or union,
+/// or is listed as an external type.
+pub struct RawApiValidator;
+
+impl RawApiValidator {
+    pub fn validate(&self, raw_api: &RawApi, external_types: &[String]) -> Result<(), UnresolvedTypesError> {
+        let mut known_types: HashSet<&String> = external_types.iter().collect();
+        known_types.extend(raw_api.raw_dtos.iter().map(|raw_dto| &raw_dto.name));
+        known_types.extend(raw_api.raw_unions.iter().map(|raw_union| &raw_union.name));
+
+        let mut unresolved_references = Vec::new();
+        let mut check = |owner: String, type_names: Vec<String>| {
+            for type_name in type_names {
+                if !known_types.contains(&type_name) {
+                    unresolved_references.push(UnresolvedReference { owner: owner.clone(), type_name })
+                }
+            }
+        };
+
+        for raw_dto in &raw_api.raw_dtos {
+            for raw_field in &raw_dto.fields {
+                check(format!("{}.{}", raw_dto.name, raw_field.name), raw_field.field_type.get_dto_names())
+            }
+        }
+        for raw_method in &raw_api.raw_methods {
+            for raw_parameter in &raw_method.parameters {
+                check(format!("{}.{}", raw_method.name, raw_parameter.name), raw_parameter.parameter_type.get_dto_names())
+            }
+            if let Some(return_type) = &raw_method.return_type {
+                check(format!("the return type of {}", raw_method.name), return_type.get_dto_names())
+            }
+        }
+        for raw_union in &raw_api.raw_unions {
+            check(format!("the union {}", raw_union.name), raw_union.members.clone())
+        }
+
+        match unresolved_references.is_empty() {
+            true => Ok(()),
+            false => Err(UnresolvedTypesError { unresolved_references })
+        }
+    }
+}
+
+/// A type that is used by the owner (like "Message.from") but never defined.
+#[derive(Debug, PartialEq)]
+pub struct UnresolvedReference {
+    pub owner: String,
+    pub type_name: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnresolvedTypesError {
+    pub unresolved_references: Vec<UnresolvedReference>,
+}
+
+impl std::error::Error for UnresolvedTypesError {}
+
+impl std::fmt::Display for UnresolvedTypesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "The following types are used but not defined. Add them to external_types in the configuration if they are provided elsewhere:")?;
+        for unresolved_reference in &self.unresolved_references {
+            writeln!(f, "{} uses {}", unresolved_reference.owner, unresolved_reference.type_name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::raw_api::RawApi;
+    use crate::raw_api::constraints::Constraints;
+    use crate::raw_api::raw_dto::RawDto;
+    use crate::raw_api::raw_field::RawField;
+    use crate::raw_api::raw_union::RawUnion;
+    use crate::raw_api::type_descriptor::TypeDescriptor;
+    use crate::raw_api::validator::{RawApiValidator, UnresolvedReference, UnresolvedTypesError};
+
+    fn create_raw_api() -> RawApi {
+        let field_type = TypeDescriptor::Optional(Box::new(TypeDescriptor::OneOf(vec![TypeDescriptor::DTO(String::from("BarDto")), TypeDescriptor::DTO(String::from("InputFile"))])));
+        let field = RawField::new(String::from("bar"), field_type, String::from("Optional. A bar."), Vec::new(), None, Constraints::default());
+        let union = RawUnion { name: String::from("FooUnion"), description: String::from("A union."), category: None, members: vec![String::from("FooDto"), String::from("BazDto")] };
+
+        RawApi {
+            raw_dtos: vec![RawDto::new(String::from("FooDto"), String::from("A foo."), vec![field]), RawDto::new(String::from("BarDto"), String::from("A bar."), Vec::new())],
+            raw_methods: Vec::new(),
+            raw_unions: vec![union],
+            categories: Vec::new()
+        }
+    }
+
+    #[test]
+    fn success_external_types() {
+        let external_types = vec![String::from("InputFile"), String::from("BazDto")];
+
+        assert_eq!(RawApiValidator.validate(&create_raw_api(), &external_types), Ok(()))
+    }
+
+    #[test]
+    fn failure_unresolved_types() {
+        let expected = UnresolvedTypesError {
+            unresolved_references: vec![
+                UnresolvedReference { owner: String::from("FooDto.bar"), type_name: String::from("InputFile") },
+                UnresolvedReference { owner: String::from("the union FooUnion"), type_name: String::from("BazDto") }
+            ]
+        };
+
+        assert_eq!(RawApiValidator.validate(&create_raw_api(), &Vec::new()), Err(expected))
+    }
+}
\ No newline at end of file
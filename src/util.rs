@@ -1,3 +1,4 @@
+#[allow(clippy::ptr_arg)]
 pub fn to_snake_case(input: &String) -> String {
     let mut result = String::new();
 
@@ -15,6 +16,7 @@ pub fn to_snake_case(input: &String) -> String {
     result
 }
 
+#[allow(clippy::ptr_arg)]
 pub fn to_camel_case(input: &String) -> String {
     let mut result = String::new();
 
@@ -29,6 +31,7 @@ pub fn to_camel_case(input: &String) -> String {
     result
 }
 
+#[allow(clippy::ptr_arg)]
 pub fn to_capital_camel_case(input: &String) -> String {
     let mut result = String::new();
 